@@ -0,0 +1,302 @@
+use std::{
+  fs::{self, File},
+  path::{Path, PathBuf},
+};
+
+use anyhow::{Result, anyhow, bail};
+use chrono::Utc;
+use clap::ValueEnum;
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tar::{Archive, Builder, Header};
+
+use crate::{
+  cache::{
+    CachedTemplate, load_templates_cache, lock::CacheLock, remove_template_dir,
+    save_templates_cache,
+  },
+  output::Render,
+  paths::OxidePaths,
+  templates::{
+    outdated::is_newer,
+    registry::{RegistryDocument, RegistryTemplate, read_registry_file},
+  },
+  utils::fs::{hash_dir, is_relative_normal, write_atomic},
+};
+
+const BUNDLE_MANIFEST: &str = "oxide-bundle.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ConflictPolicy {
+  Newer,
+  Keep,
+  Replace,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BundleManifest {
+  #[serde(rename = "createdAt")]
+  pub created_at: String,
+  pub templates: Vec<CachedTemplate>,
+  pub registry: Option<Vec<RegistryTemplate>>,
+}
+
+#[derive(Serialize)]
+pub struct ExportSummary {
+  pub bundle: PathBuf,
+  pub templates: Vec<String>,
+}
+
+#[derive(Serialize, Default)]
+pub struct ImportSummary {
+  pub imported: Vec<String>,
+  pub replaced: Vec<String>,
+  pub skipped: Vec<String>,
+  pub rejected: Vec<String>,
+  #[serde(rename = "registryUpdated")]
+  pub registry_updated: bool,
+}
+
+pub fn export_bundle(
+  oxide_paths: &OxidePaths,
+  bundle_path: &Path,
+  names: &[String],
+) -> Result<ExportSummary> {
+  let templates_info = load_templates_cache(&oxide_paths.templates)?;
+
+  let unknown: Vec<&String> = names
+    .iter()
+    .filter(|n| !templates_info.templates.iter().any(|t| &&t.name == n))
+    .collect();
+  if !unknown.is_empty() {
+    bail!(
+      "Templates not installed: {}",
+      unknown
+        .iter()
+        .map(|n| n.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+    );
+  }
+
+  let templates: Vec<CachedTemplate> = templates_info
+    .templates
+    .into_iter()
+    .filter(|t| names.is_empty() || names.contains(&t.name))
+    .collect();
+
+  let registry = if oxide_paths.registry.exists() {
+    Some(read_registry_file(&oxide_paths.registry)?.into_templates())
+  } else {
+    None
+  };
+
+  let file = File::create(bundle_path)?;
+  let mut builder = Builder::new(GzEncoder::new(file, Compression::default()));
+
+  for template in &templates {
+    builder.append_dir_all(
+      format!("templates/{}", template.path),
+      oxide_paths.templates.join(&template.path),
+    )?;
+  }
+
+  let exported = templates.iter().map(|t| t.name.clone()).collect();
+  let manifest = serde_json::to_vec_pretty(&BundleManifest {
+    created_at: Utc::now().to_rfc3339(),
+    templates,
+    registry,
+  })?;
+
+  let mut header = Header::new_gnu();
+  header.set_size(manifest.len() as u64);
+  header.set_mode(0o644);
+  header.set_mtime(Utc::now().timestamp() as u64);
+  header.set_cksum();
+  builder.append_data(&mut header, BUNDLE_MANIFEST, manifest.as_slice())?;
+
+  builder.into_inner()?.finish()?;
+
+  Ok(ExportSummary {
+    bundle: bundle_path.to_path_buf(),
+    templates: exported,
+  })
+}
+
+pub fn import_bundle(
+  oxide_paths: &OxidePaths,
+  bundle_path: &Path,
+  policy: ConflictPolicy,
+) -> Result<ImportSummary> {
+  let unpacked = tempfile::tempdir()?;
+  Archive::new(GzDecoder::new(File::open(bundle_path)?)).unpack(unpacked.path())?;
+
+  let manifest_path = unpacked.path().join(BUNDLE_MANIFEST);
+  let manifest: BundleManifest = serde_json::from_str(
+    &fs::read_to_string(&manifest_path)
+      .map_err(|_| anyhow!("{} is not an oxide bundle", bundle_path.display()))?,
+  )?;
+
+  let template_path = &oxide_paths.templates;
+  let _lock = CacheLock::acquire(template_path)?;
+  let mut templates_info = load_templates_cache(template_path)?;
+  let mut summary = ImportSummary::default();
+
+  for mut template in manifest.templates {
+    if !is_relative_normal(Path::new(&template.path)) {
+      warn!(
+        "⚠ Bundle entry '{}' has an unsafe path '{}'",
+        template.name, template.path
+      );
+      summary.rejected.push(template.name);
+      continue;
+    }
+
+    let source = unpacked.path().join("templates").join(&template.path);
+
+    if !source.join("oxide.template.json").exists()
+      || (!template.files.is_empty() && hash_dir(&source)? != template.files)
+    {
+      warn!(
+        "⚠ Bundle copy of '{}' is incomplete or modified",
+        template.name
+      );
+      summary.rejected.push(template.name);
+      continue;
+    }
+
+    let existing = templates_info
+      .templates
+      .iter()
+      .position(|t| t.name == template.name);
+
+    if let Some(index) = existing {
+      let current = &templates_info.templates[index];
+      let replace = match policy {
+        ConflictPolicy::Keep => false,
+        ConflictPolicy::Replace => true,
+        ConflictPolicy::Newer => is_newer(&template.version, &current.version),
+      };
+
+      if !replace {
+        summary.skipped.push(template.name);
+        continue;
+      }
+
+      if !is_relative_normal(Path::new(&current.path)) {
+        bail!(
+          "Cached template '{}' has an unsafe path '{}'. Run `oxide cache repair`",
+          current.name,
+          current.path
+        );
+      }
+      remove_template_dir(template_path, &template_path.join(&current.path))?;
+      templates_info.templates.remove(index);
+    }
+
+    let destination = template_path.join(&template.path);
+    remove_template_dir(template_path, &destination)?;
+    fs::create_dir_all(&destination)?;
+    fs_extra::dir::copy(
+      &source,
+      &destination,
+      &fs_extra::dir::CopyOptions::new().content_only(true),
+    )?;
+
+    template.files = hash_dir(&destination)?;
+    template.installed_at = Some(Utc::now().to_rfc3339());
+
+    if existing.is_some() {
+      summary.replaced.push(template.name.clone());
+    } else {
+      summary.imported.push(template.name.clone());
+    }
+    templates_info.templates.push(template);
+  }
+
+  templates_info.last_updated = Utc::now().to_rfc3339();
+  save_templates_cache(template_path, &templates_info)?;
+
+  if let Some(bundle_registry) = manifest.registry {
+    summary.registry_updated = merge_registry(&oxide_paths.registry, bundle_registry)?;
+  }
+
+  Ok(summary)
+}
+
+fn merge_registry(registry_file: &Path, bundle_registry: Vec<RegistryTemplate>) -> Result<bool> {
+  let mut registry = if registry_file.exists() {
+    read_registry_file(registry_file)?
+  } else {
+    RegistryDocument::Listing(Vec::new())
+  };
+
+  let templates = registry.templates_mut();
+  let before = templates.len();
+  for entry in bundle_registry {
+    if !templates.iter().any(|t| t.name == entry.name) {
+      templates.push(entry);
+    }
+  }
+
+  if templates.len() == before && registry_file.exists() {
+    return Ok(false);
+  }
+
+  write_atomic(registry_file, serde_json::to_string_pretty(&registry)?)?;
+  Ok(true)
+}
+
+impl Render for ExportSummary {
+  fn to_table(&self) -> String {
+    format!(
+      "✓ Exported {} template(s) to {}",
+      self.templates.len(),
+      self.bundle.display()
+    )
+  }
+
+  fn to_plain(&self) -> String {
+    self.templates.join("\n")
+  }
+}
+
+impl Render for ImportSummary {
+  fn to_table(&self) -> String {
+    let mut lines = Vec::new();
+
+    for name in &self.imported {
+      lines.push(format!("✓ Imported {}", name));
+    }
+    for name in &self.replaced {
+      lines.push(format!("✓ Replaced {}", name));
+    }
+    for name in &self.skipped {
+      lines.push(format!("- Kept existing {}", name));
+    }
+    for name in &self.rejected {
+      lines.push(format!("✗ Rejected {}", name));
+    }
+    if self.registry_updated {
+      lines.push("✓ Merged registry snapshot".to_string());
+    }
+
+    if lines.is_empty() {
+      lines.push("Bundle contains no templates".to_string());
+    }
+
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    let mut lines = Vec::new();
+
+    lines.extend(self.imported.iter().map(|n| format!("imported\t{}", n)));
+    lines.extend(self.replaced.iter().map(|n| format!("replaced\t{}", n)));
+    lines.extend(self.skipped.iter().map(|n| format!("skipped\t{}", n)));
+    lines.extend(self.rejected.iter().map(|n| format!("rejected\t{}", n)));
+
+    lines.join("\n")
+  }
+}
@@ -0,0 +1,51 @@
+use std::{
+  fs::{self, File, OpenOptions, TryLockError},
+  path::Path,
+};
+
+use anyhow::Result;
+use log::{info, trace};
+
+use crate::utils::fs::relative_key;
+
+pub const CACHE_LOCK: &str = ".oxide-templates.lock";
+
+pub struct CacheLock {
+  _file: File,
+}
+
+impl CacheLock {
+  pub fn acquire(template_path: &Path) -> Result<Self> {
+    Self::lock_file(&template_path.join(CACHE_LOCK))
+  }
+
+  pub fn template(template_path: &Path, path: &Path) -> Result<Self> {
+    let name = relative_key(path).replace('/', "-");
+    Self::lock_file(&template_path.join(format!(".{}.lock", name)))
+  }
+
+  fn lock_file(lock_path: &Path) -> Result<Self> {
+    if let Some(parent) = lock_path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    let file = OpenOptions::new()
+      .create(true)
+      .read(true)
+      .write(true)
+      .truncate(false)
+      .open(lock_path)?;
+
+    match file.try_lock() {
+      Ok(()) => {}
+      Err(TryLockError::WouldBlock) => {
+        info!("⏳ Waiting for another oxide process to finish...");
+        file.lock()?;
+      }
+      Err(TryLockError::Error(e)) => return Err(e.into()),
+    }
+
+    trace!("Acquired lock {}", lock_path.display());
+    Ok(Self { _file: file })
+  }
+}
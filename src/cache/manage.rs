@@ -0,0 +1,299 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use comfy_table::{Attribute, Cell, Table};
+use serde::Serialize;
+
+use crate::{
+  cache::{
+    CachedTemplate, find_cached_template, load_templates_cache,
+    lock::{CACHE_LOCK, CacheLock},
+    remove_template_dir, save_templates_cache,
+  },
+  config::OxideConfig,
+  output::Render,
+  paths::OxidePaths,
+  project::events::EventSink,
+  templates::{install::download_template, registry::get_registry},
+  utils::{
+    cancel::CancelToken,
+    fs::{dir_stats, format_size},
+  },
+};
+
+#[derive(Serialize)]
+pub struct CacheUsage {
+  pub templates: Vec<TemplateUsage>,
+  pub total: u64,
+}
+
+#[derive(Serialize)]
+pub struct TemplateUsage {
+  pub name: String,
+  pub path: String,
+  #[serde(rename = "fileCount")]
+  pub file_count: usize,
+  pub size: u64,
+  #[serde(rename = "lastUsed")]
+  pub last_used: Option<String>,
+}
+
+#[derive(Serialize, Default)]
+pub struct WarmSummary {
+  pub downloaded: Vec<String>,
+  pub cached: Vec<String>,
+  #[serde(rename = "notFound")]
+  pub not_found: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct PruneSummary {
+  pub removed: Vec<String>,
+  pub freed: u64,
+}
+
+pub async fn warm_cache(
+  oxide_paths: &OxidePaths,
+  names: &[String],
+  config: &OxideConfig,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<WarmSummary> {
+  let registry = get_registry(&oxide_paths.registry, config).await?;
+  let mut summary = WarmSummary::default();
+  let mut to_download = Vec::new();
+
+  for name in names {
+    let Some(entry) = registry.iter().find(|t| &t.name == name) else {
+      summary.not_found.push(name.clone());
+      continue;
+    };
+
+    let path = PathBuf::from(&entry.path);
+    if find_cached_template(&oxide_paths.templates, &path)?.is_some() {
+      summary.cached.push(name.clone());
+    } else {
+      to_download.push((name.clone(), path));
+    }
+  }
+
+  if !to_download.is_empty() {
+    let missing: Vec<String> = to_download
+      .iter()
+      .map(|(name, _)| format!("template '{}'", name))
+      .collect();
+    config.ensure_online(&missing)?;
+  }
+
+  for (name, path) in to_download {
+    download_template(&oxide_paths.templates, &path, config, cancel, events).await?;
+    summary.downloaded.push(name);
+  }
+
+  Ok(summary)
+}
+
+pub fn get_cache_usage(oxide_paths: &OxidePaths) -> Result<CacheUsage> {
+  let templates_info = load_templates_cache(&oxide_paths.templates)?;
+
+  let templates = templates_info
+    .templates
+    .into_iter()
+    .map(|t| {
+      let (file_count, size) = dir_stats(&oxide_paths.templates.join(&t.path));
+      TemplateUsage {
+        name: t.name,
+        path: t.path,
+        file_count,
+        size,
+        last_used: t.last_used,
+      }
+    })
+    .collect();
+
+  let (_, total) = dir_stats(&oxide_paths.cache);
+
+  Ok(CacheUsage { templates, total })
+}
+
+pub fn prune_cache(oxide_paths: &OxidePaths, days: u64) -> Result<PruneSummary> {
+  let template_path = &oxide_paths.templates;
+  let _lock = CacheLock::acquire(template_path)?;
+  let mut templates_info = load_templates_cache(template_path)?;
+  let cutoff = Utc::now() - Duration::days(days as i64);
+
+  let mut summary = PruneSummary {
+    removed: Vec::new(),
+    freed: 0,
+  };
+
+  let (stale, fresh): (Vec<CachedTemplate>, Vec<CachedTemplate>) = templates_info
+    .templates
+    .into_iter()
+    .partition(|t| last_used_at(template_path, t).is_none_or(|used| used < cutoff));
+
+  for template in stale {
+    let install_path = template_path.join(&template.path);
+    let (_, size) = dir_stats(&install_path);
+
+    remove_template_dir(template_path, &install_path)?;
+    summary.freed += size;
+    summary.removed.push(template.name);
+  }
+
+  templates_info.templates = fresh;
+  templates_info.last_updated = Utc::now().to_rfc3339();
+  save_templates_cache(template_path, &templates_info)?;
+
+  Ok(summary)
+}
+
+fn last_used_at(template_path: &Path, template: &CachedTemplate) -> Option<DateTime<Utc>> {
+  let timestamp = template
+    .last_used
+    .as_deref()
+    .or(template.installed_at.as_deref())
+    .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+    .map(|t| t.with_timezone(&Utc));
+
+  timestamp.or_else(|| {
+    let manifest = template_path
+      .join(&template.path)
+      .join("oxide.template.json");
+    let modified = fs::metadata(manifest).ok()?.modified().ok()?;
+    Some(DateTime::<Utc>::from(modified))
+  })
+}
+
+pub fn clean_cache(oxide_paths: &OxidePaths) -> Result<u64> {
+  let _lock = CacheLock::acquire(&oxide_paths.templates)?;
+  let (_, freed) = dir_stats(&oxide_paths.cache);
+
+  clear_dir(&oxide_paths.cache, &oxide_paths.templates.join(CACHE_LOCK))?;
+  for file in [
+    oxide_paths.registry.clone(),
+    oxide_paths.registry.with_extension("meta.json"),
+  ] {
+    if file.exists() {
+      fs::remove_file(&file)?;
+    }
+  }
+
+  oxide_paths.ensure_directories()?;
+
+  Ok(freed)
+}
+
+fn clear_dir(dir: &Path, keep: &Path) -> Result<()> {
+  for entry in fs::read_dir(dir)? {
+    let path = entry?.path();
+    if path == keep {
+      continue;
+    }
+
+    if keep.starts_with(&path) {
+      clear_dir(&path, keep)?;
+    } else if path.is_dir() {
+      fs::remove_dir_all(&path)?;
+    } else {
+      fs::remove_file(&path)?;
+    }
+  }
+
+  Ok(())
+}
+
+impl Render for CacheUsage {
+  fn to_table(&self) -> String {
+    let mut lines = Vec::new();
+
+    if !self.templates.is_empty() {
+      let mut table = Table::new();
+
+      table.set_header(vec![
+        Cell::new("Name").add_attribute(Attribute::Bold),
+        Cell::new("Files").add_attribute(Attribute::Bold),
+        Cell::new("Size").add_attribute(Attribute::Bold),
+        Cell::new("Last used").add_attribute(Attribute::Bold),
+      ]);
+
+      for template in &self.templates {
+        table.add_row(vec![
+          Cell::new(&template.name),
+          Cell::new(template.file_count),
+          Cell::new(format_size(template.size)),
+          Cell::new(template.last_used.as_deref().unwrap_or("never")),
+        ]);
+      }
+
+      lines.push(table.to_string());
+    }
+
+    lines.push(format!("Total cache size: {}", format_size(self.total)));
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    let mut lines: Vec<String> = self
+      .templates
+      .iter()
+      .map(|t| format!("{}\t{}\t{}", t.name, t.file_count, t.size))
+      .collect();
+
+    lines.push(format!("total\t{}", self.total));
+    lines.join("\n")
+  }
+}
+
+impl Render for WarmSummary {
+  fn to_table(&self) -> String {
+    let mut lines = Vec::new();
+
+    for name in &self.downloaded {
+      lines.push(format!("✓ Downloaded {}", name));
+    }
+    for name in &self.cached {
+      lines.push(format!("✓ {} is already cached", name));
+    }
+    for name in &self.not_found {
+      lines.push(format!("✗ {} not found in registry", name));
+    }
+
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    let mut lines = Vec::new();
+
+    lines.extend(self.downloaded.iter().map(|n| format!("downloaded\t{}", n)));
+    lines.extend(self.cached.iter().map(|n| format!("cached\t{}", n)));
+    lines.extend(self.not_found.iter().map(|n| format!("notFound\t{}", n)));
+
+    lines.join("\n")
+  }
+}
+
+impl Render for PruneSummary {
+  fn to_table(&self) -> String {
+    if self.removed.is_empty() {
+      return "✓ Nothing to prune".to_string();
+    }
+
+    let mut lines: Vec<String> = self
+      .removed
+      .iter()
+      .map(|name| format!("✓ Removed {}", name))
+      .collect();
+
+    lines.push(format!("Freed {}", format_size(self.freed)));
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    self.removed.join("\n")
+  }
+}
@@ -0,0 +1,232 @@
+pub mod bundle;
+pub mod lock;
+pub mod manage;
+pub mod verify;
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{Result, anyhow, bail};
+use chrono::Utc;
+use comfy_table::{Attribute, Cell, Color, Table};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  cache::lock::CacheLock,
+  error::OxideError,
+  output::Render,
+  templates::OxideTemplate,
+  utils::fs::{hash_dir, is_relative_normal, write_atomic},
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct TemplatesCache {
+  #[serde(rename = "lastUpdated")]
+  pub last_updated: String,
+  pub templates: Vec<CachedTemplate>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CachedTemplate {
+  pub name: String,
+  pub version: String,
+  pub source: String,
+  pub path: String,
+  pub official: bool,
+  #[serde(rename = "installedAt", default)]
+  pub installed_at: Option<String>,
+  #[serde(rename = "lastUsed", default)]
+  pub last_used: Option<String>,
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub files: BTreeMap<String, String>,
+}
+
+pub fn load_templates_cache(template_path: &Path) -> Result<TemplatesCache> {
+  let templates_json = template_path.join("oxide-templates.json");
+
+  if !templates_json.exists() {
+    return Ok(TemplatesCache {
+      last_updated: Utc::now().to_rfc3339(),
+      templates: Vec::new(),
+    });
+  }
+
+  let content = fs::read_to_string(&templates_json)?;
+  Ok(serde_json::from_str(&content)?)
+}
+
+pub fn save_templates_cache(template_path: &Path, templates_info: &TemplatesCache) -> Result<()> {
+  write_atomic(
+    &template_path.join("oxide-templates.json"),
+    serde_json::to_string_pretty(templates_info)?,
+  )
+}
+
+pub fn find_cached_template(template_path: &Path, path: &Path) -> Result<Option<CachedTemplate>> {
+  let templates_info = load_templates_cache(template_path)?;
+  let path = path.to_string_lossy();
+
+  Ok(
+    templates_info
+      .templates
+      .into_iter()
+      .find(|t| t.path == path && template_path.join(&t.path).exists()),
+  )
+}
+
+pub fn update_templates_cache(template_path: &Path, path: &Path) -> Result<()> {
+  let oxide_json = template_path.join(path).join("oxide.template.json");
+  let content = fs::read_to_string(&oxide_json)?;
+  let template_info: OxideTemplate =
+    serde_json::from_str(&content).map_err(|source| OxideError::InvalidManifest {
+      path: oxide_json.display().to_string(),
+      source,
+    })?;
+  let files = hash_dir(&template_path.join(path))?;
+
+  let _lock = CacheLock::acquire(template_path)?;
+  let mut templates_info = load_templates_cache(template_path)?;
+
+  templates_info.last_updated = Utc::now().to_rfc3339();
+
+  templates_info
+    .templates
+    .retain(|t| t.name != template_info.name);
+
+  templates_info.templates.push(CachedTemplate {
+    name: template_info.name,
+    version: template_info.version,
+    source: template_info.repository.url,
+    path: path.to_string_lossy().to_string(),
+    official: template_info.official,
+    installed_at: Some(Utc::now().to_rfc3339()),
+    last_used: None,
+    files,
+  });
+
+  save_templates_cache(template_path, &templates_info)
+}
+
+pub fn touch_template(template_path: &Path, path: &Path) -> Result<()> {
+  let _lock = CacheLock::acquire(template_path)?;
+  let mut templates_info = load_templates_cache(template_path)?;
+  let path = path.to_string_lossy();
+
+  let Some(template) = templates_info.templates.iter_mut().find(|t| t.path == path) else {
+    return Ok(());
+  };
+
+  template.last_used = Some(Utc::now().to_rfc3339());
+  save_templates_cache(template_path, &templates_info)
+}
+
+pub fn remove_template_dir(template_path: &Path, cleanup_path: &Path) -> Result<()> {
+  let inside = cleanup_path
+    .strip_prefix(template_path)
+    .is_ok_and(is_relative_normal);
+  if !inside {
+    bail!(
+      "Refusing to remove {}: it is outside the template cache",
+      cleanup_path.display()
+    );
+  }
+
+  if !cleanup_path.exists() {
+    return Ok(());
+  }
+
+  fs::remove_dir_all(cleanup_path)?;
+
+  let mut current = cleanup_path.parent();
+  while let Some(parent) = current {
+    if parent == template_path {
+      break;
+    }
+    if fs::remove_dir(parent).is_err() {
+      break;
+    }
+    current = parent.parent();
+  }
+
+  Ok(())
+}
+
+pub fn remove_template_from_cache(template_path: &Path, template_name: &str) -> Result<()> {
+  let _lock = CacheLock::acquire(template_path)?;
+  let mut templates_info = load_templates_cache(template_path)?;
+
+  let template = templates_info
+    .templates
+    .iter()
+    .find(|t| t.name == template_name)
+    .ok_or_else(|| anyhow!("Template '{}' is not installed", template_name))?;
+
+  remove_template_dir(template_path, &template_path.join(&template.path))?;
+
+  templates_info.last_updated = Utc::now().to_rfc3339();
+  templates_info
+    .templates
+    .retain(|template| template.name != template_name);
+
+  save_templates_cache(template_path, &templates_info)?;
+
+  info!("✓ Removed template {}", template_name);
+  Ok(())
+}
+
+pub fn get_installed_templates(template_path: &Path) -> Result<TemplatesCache> {
+  load_templates_cache(template_path)
+}
+
+impl Render for TemplatesCache {
+  fn to_table(&self) -> String {
+    if self.templates.is_empty() {
+      return "No templates installed yet.".to_string();
+    }
+
+    let mut table = Table::new();
+
+    table.set_header(vec![
+      Cell::new("Name").add_attribute(Attribute::Bold),
+      Cell::new("Version").add_attribute(Attribute::Bold),
+      Cell::new("Official").add_attribute(Attribute::Bold),
+    ]);
+
+    for template in &self.templates {
+      table.add_row(vec![
+        Cell::new(&template.name),
+        Cell::new(&template.version),
+        Cell::new(if template.official { "✓" } else { "✗" }).fg(if template.official {
+          Color::Green
+        } else {
+          Color::Red
+        }),
+      ]);
+    }
+
+    format!(
+      "\nInstalled templates (last updated: {}):\n{table}",
+      self.last_updated
+    )
+  }
+
+  fn to_plain(&self) -> String {
+    self
+      .templates
+      .iter()
+      .map(|t| format!("{}\t{}\t{}", t.name, t.version, t.official))
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+pub fn is_template_installed(template_name: &str, template_path: &Path) -> Result<bool> {
+  let templates_info = load_templates_cache(template_path)?;
+
+  Ok(
+    templates_info
+      .templates
+      .iter()
+      .any(|t| t.name == template_name),
+  )
+}
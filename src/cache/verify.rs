@@ -0,0 +1,386 @@
+use std::{
+  collections::HashSet,
+  fs,
+  path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use chrono::Utc;
+use comfy_table::{Attribute, Cell, Color, Table};
+use log::warn;
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use crate::{
+  cache::{load_templates_cache, lock::CacheLock, remove_template_dir, save_templates_cache},
+  config::OxideConfig,
+  output::Render,
+  project::events::EventSink,
+  templates::install::download_template,
+  utils::{
+    cancel::CancelToken,
+    fs::{hash_file, relative_key},
+  },
+};
+
+#[derive(Serialize)]
+pub struct CacheReport {
+  pub templates: Vec<TemplateReport>,
+  pub duplicates: Vec<String>,
+  #[serde(rename = "orphanedPaths")]
+  pub orphaned_paths: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct TemplateReport {
+  pub name: String,
+  pub path: String,
+  #[serde(rename = "missingDir")]
+  pub missing_dir: bool,
+  pub missing: Vec<String>,
+  pub modified: Vec<String>,
+  pub orphaned: Vec<String>,
+}
+
+#[derive(Serialize, Default)]
+pub struct RepairSummary {
+  pub deduplicated: Vec<String>,
+  pub redownloaded: Vec<String>,
+  pub dropped: Vec<String>,
+  pub removed: Vec<String>,
+}
+
+impl TemplateReport {
+  pub fn is_broken(&self) -> bool {
+    self.missing_dir || !self.missing.is_empty() || !self.modified.is_empty()
+  }
+
+  pub fn is_healthy(&self) -> bool {
+    !self.is_broken() && self.orphaned.is_empty()
+  }
+
+  fn status(&self) -> String {
+    if self.missing_dir {
+      return "directory missing".to_string();
+    }
+
+    let mut problems = Vec::new();
+    if !self.missing.is_empty() {
+      problems.push(format!("{} missing", self.missing.len()));
+    }
+    if !self.modified.is_empty() {
+      problems.push(format!("{} modified", self.modified.len()));
+    }
+    if !self.orphaned.is_empty() {
+      problems.push(format!("{} orphaned", self.orphaned.len()));
+    }
+
+    if problems.is_empty() {
+      "ok".to_string()
+    } else {
+      problems.join(", ")
+    }
+  }
+}
+
+impl CacheReport {
+  pub fn is_healthy(&self) -> bool {
+    self.duplicates.is_empty()
+      && self.orphaned_paths.is_empty()
+      && self.templates.iter().all(|t| t.is_healthy())
+  }
+}
+
+pub fn verify_cache(template_path: &Path) -> Result<CacheReport> {
+  let templates_info = load_templates_cache(template_path)?;
+
+  let mut seen = HashSet::new();
+  let mut duplicates = Vec::new();
+  for template in &templates_info.templates {
+    if !seen.insert(template.name.as_str()) && !duplicates.contains(&template.name) {
+      duplicates.push(template.name.clone());
+    }
+  }
+
+  let mut templates = Vec::new();
+  for template in &templates_info.templates {
+    let install_path = template_path.join(&template.path);
+
+    let mut report = TemplateReport {
+      name: template.name.clone(),
+      path: template.path.clone(),
+      missing_dir: !install_path.join("oxide.template.json").exists(),
+      missing: Vec::new(),
+      modified: Vec::new(),
+      orphaned: Vec::new(),
+    };
+
+    if !report.missing_dir && !template.files.is_empty() {
+      for (file, hash) in &template.files {
+        let file_path = install_path.join(file);
+        if !file_path.exists() {
+          report.missing.push(file.clone());
+        } else if &hash_file(&file_path)? != hash {
+          report.modified.push(file.clone());
+        }
+      }
+
+      for entry in WalkDir::new(&install_path) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+          continue;
+        }
+
+        let key = relative_key(entry.path().strip_prefix(&install_path)?);
+        if !template.files.contains_key(&key) {
+          report.orphaned.push(key);
+        }
+      }
+    }
+
+    templates.push(report);
+  }
+
+  let cached_paths: Vec<PathBuf> = templates_info
+    .templates
+    .iter()
+    .map(|t| PathBuf::from(&t.path))
+    .collect();
+
+  Ok(CacheReport {
+    templates,
+    duplicates,
+    orphaned_paths: find_orphaned_paths(template_path, &cached_paths)?,
+  })
+}
+
+fn find_orphaned_paths(template_path: &Path, cached_paths: &[PathBuf]) -> Result<Vec<String>> {
+  let mut orphaned = Vec::new();
+
+  for entry in WalkDir::new(template_path).min_depth(2) {
+    let entry = entry?;
+    if !entry.file_type().is_file() {
+      continue;
+    }
+
+    let relative_path = entry.path().strip_prefix(template_path)?;
+    let mut orphan_root = relative_path.to_path_buf();
+    let mut prefix = PathBuf::new();
+    let mut belongs_to_template = false;
+
+    for component in relative_path
+      .parent()
+      .into_iter()
+      .flat_map(|p| p.components())
+    {
+      prefix.push(component);
+
+      if cached_paths.iter().any(|c| prefix.starts_with(c)) {
+        belongs_to_template = true;
+        break;
+      }
+
+      if !cached_paths.iter().any(|c| c.starts_with(&prefix)) {
+        orphan_root = prefix.clone();
+        break;
+      }
+    }
+
+    let key = relative_key(&orphan_root);
+    if !belongs_to_template && !orphaned.contains(&key) {
+      orphaned.push(key);
+    }
+  }
+
+  Ok(orphaned)
+}
+
+pub async fn repair_cache(
+  template_path: &Path,
+  config: &OxideConfig,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<RepairSummary> {
+  let lock = CacheLock::acquire(template_path)?;
+  let report = verify_cache(template_path)?;
+  let mut summary = RepairSummary::default();
+  let mut templates_info = load_templates_cache(template_path)?;
+
+  if !report.duplicates.is_empty() {
+    let mut seen = HashSet::new();
+    templates_info
+      .templates
+      .retain(|template| seen.insert(template.name.clone()));
+    summary.deduplicated = report.duplicates.clone();
+  }
+
+  for orphan in &report.orphaned_paths {
+    let orphan_path = template_path.join(orphan);
+    if orphan_path.is_dir() {
+      remove_template_dir(template_path, &orphan_path)?;
+    } else {
+      fs::remove_file(&orphan_path)?;
+    }
+    summary.removed.push(orphan.clone());
+  }
+
+  let mut seen = HashSet::new();
+  let mut broken = Vec::new();
+  for template_report in &report.templates {
+    if !seen.insert(template_report.name.as_str()) {
+      continue;
+    }
+
+    if template_report.is_broken() {
+      broken.push((template_report.name.clone(), template_report.path.clone()));
+      continue;
+    }
+
+    let install_path = template_path.join(&template_report.path);
+    for orphan in &template_report.orphaned {
+      fs::remove_file(install_path.join(orphan))?;
+      summary
+        .removed
+        .push(format!("{}/{}", template_report.path, orphan));
+    }
+  }
+
+  templates_info
+    .templates
+    .retain(|t| !broken.iter().any(|(name, _)| name == &t.name));
+  templates_info.last_updated = Utc::now().to_rfc3339();
+  save_templates_cache(template_path, &templates_info)?;
+  drop(lock);
+
+  for (name, path) in broken {
+    let install_path = template_path.join(&path);
+    remove_template_dir(template_path, &install_path)?;
+
+    match download_template(template_path, &PathBuf::from(&path), config, cancel, events).await {
+      Ok(()) => summary.redownloaded.push(name),
+      Err(e) if cancel.is_cancelled() => return Err(e),
+      Err(e) => {
+        warn!("⚠ Could not re-download '{}': {}", name, e);
+        remove_template_dir(template_path, &install_path)?;
+        summary.dropped.push(name);
+      }
+    }
+  }
+
+  Ok(summary)
+}
+
+impl Render for CacheReport {
+  fn to_table(&self) -> String {
+    let mut lines = Vec::new();
+
+    if self.templates.is_empty() {
+      lines.push("No templates installed yet.".to_string());
+    } else {
+      let mut table = Table::new();
+
+      table.set_header(vec![
+        Cell::new("Name").add_attribute(Attribute::Bold),
+        Cell::new("Path").add_attribute(Attribute::Bold),
+        Cell::new("Status").add_attribute(Attribute::Bold),
+      ]);
+
+      for template in &self.templates {
+        table.add_row(vec![
+          Cell::new(&template.name),
+          Cell::new(&template.path),
+          Cell::new(template.status()).fg(if template.is_healthy() {
+            Color::Green
+          } else {
+            Color::Red
+          }),
+        ]);
+      }
+
+      lines.push(table.to_string());
+    }
+
+    if !self.duplicates.is_empty() {
+      lines.push(format!(
+        "⚠ Duplicate entries: {}",
+        self.duplicates.join(", ")
+      ));
+    }
+
+    for orphan in &self.orphaned_paths {
+      lines.push(format!("⚠ Orphaned path: {}", orphan));
+    }
+
+    if self.is_healthy() {
+      lines.push("✓ Cache is healthy".to_string());
+    } else {
+      lines.push("Run `oxide cache repair` to fix these problems".to_string());
+    }
+
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    let mut lines: Vec<String> = self
+      .templates
+      .iter()
+      .map(|t| format!("{}\t{}\t{}", t.name, t.path, t.status()))
+      .collect();
+
+    lines.extend(self.duplicates.iter().map(|d| format!("duplicate\t{}", d)));
+    lines.extend(
+      self
+        .orphaned_paths
+        .iter()
+        .map(|o| format!("orphaned\t{}", o)),
+    );
+
+    lines.join("\n")
+  }
+}
+
+impl Render for RepairSummary {
+  fn to_table(&self) -> String {
+    let mut lines = Vec::new();
+
+    for name in &self.deduplicated {
+      lines.push(format!("✓ Removed duplicate entries for {}", name));
+    }
+    for path in &self.removed {
+      lines.push(format!("✓ Removed orphaned {}", path));
+    }
+    for name in &self.redownloaded {
+      lines.push(format!("✓ Re-downloaded {}", name));
+    }
+    for name in &self.dropped {
+      lines.push(format!("✗ Dropped {}", name));
+    }
+
+    if lines.is_empty() {
+      lines.push("✓ Nothing to repair".to_string());
+    }
+
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    let mut lines = Vec::new();
+
+    lines.extend(
+      self
+        .deduplicated
+        .iter()
+        .map(|n| format!("deduplicated\t{}", n)),
+    );
+    lines.extend(self.removed.iter().map(|p| format!("removed\t{}", p)));
+    lines.extend(
+      self
+        .redownloaded
+        .iter()
+        .map(|n| format!("redownloaded\t{}", n)),
+    );
+    lines.extend(self.dropped.iter().map(|n| format!("dropped\t{}", n)));
+
+    lines.join("\n")
+  }
+}
@@ -1,6 +1,15 @@
+use std::path::PathBuf;
+
 use clap::Subcommand;
 
-use crate::prompts::{BuildTool, Language, PackageManager, ProjectLayer};
+use crate::{
+  cache::bundle::ConflictPolicy,
+  project::{
+    conflict::FileConflictPolicy,
+    workspace::{MemberSpec, WorkspaceTool},
+  },
+  prompts::{Language, PackageManager},
+};
 
 #[derive(Subcommand)]
 pub enum Commands {
@@ -8,13 +17,13 @@ pub enum Commands {
     name: Option<String>,
 
     #[arg(short, long)]
-    layer: Option<ProjectLayer>,
+    layer: Option<String>,
 
     #[arg(short, long)]
     framework: Option<String>,
 
     #[arg(short, long)]
-    build_tool: Option<BuildTool>,
+    build_tool: Option<String>,
 
     #[arg(short = 'L', long, alias = "lang")]
     language: Option<Language>,
@@ -24,19 +33,56 @@ pub enum Commands {
 
     #[arg(short = 'm', long)]
     package_manager: Option<PackageManager>,
+
+    #[arg(long, value_enum)]
+    conflict: Option<FileConflictPolicy>,
+
+    #[arg(long)]
+    dry_run: bool,
+
+    #[arg(long, value_name = "PATH", requires = "dry_run")]
+    show: Option<PathBuf>,
+
+    #[arg(long, group = "monorepo", conflicts_with_all = ["layer", "framework", "build_tool", "language", "platform", "dry_run"])]
+    workspace: bool,
+
+    #[arg(long, group = "monorepo", conflicts_with_all = ["layer", "framework", "build_tool", "language", "platform", "dry_run"])]
+    fullstack: bool,
+
+    #[arg(long, value_name = "TEMPLATE", requires = "fullstack")]
+    frontend: Option<String>,
+
+    #[arg(long, value_name = "TEMPLATE", requires = "fullstack")]
+    backend: Option<String>,
+
+    #[arg(long, value_enum, requires = "monorepo")]
+    tool: Option<WorkspaceTool>,
+
+    #[arg(long = "app", value_name = "DIR=TEMPLATE", requires = "monorepo")]
+    apps: Vec<MemberSpec>,
+  },
+
+  Add {
+    feature: String,
+
+    #[arg(short = 'C', long, value_name = "DIR", default_value = ".")]
+    project: PathBuf,
+
+    #[arg(long, value_enum)]
+    conflict: Option<FileConflictPolicy>,
   },
 
   Install {
     template_name: Option<String>,
 
     #[arg(short, long)]
-    layer: Option<ProjectLayer>,
+    layer: Option<String>,
 
     #[arg(short, long)]
     framework: Option<String>,
 
     #[arg(short, long)]
-    build_tool: Option<BuildTool>,
+    build_tool: Option<String>,
 
     #[arg(short = 'L', long, alias = "lang")]
     language: Option<Language>,
@@ -50,4 +96,128 @@ pub enum Commands {
   },
 
   Installed {},
+
+  List {
+    #[arg(long)]
+    available: bool,
+
+    #[arg(short, long, requires = "available")]
+    layer: Option<String>,
+
+    #[arg(short, long, requires = "available")]
+    framework: Option<String>,
+
+    #[arg(short = 'L', long, alias = "lang", requires = "available")]
+    language: Option<Language>,
+  },
+
+  Search {
+    query: String,
+
+    #[arg(short, long)]
+    layer: Option<String>,
+
+    #[arg(short, long)]
+    framework: Option<String>,
+
+    #[arg(short = 'L', long, alias = "lang")]
+    language: Option<Language>,
+  },
+
+  Info {
+    template_name: String,
+  },
+
+  Outdated {},
+
+  Config {
+    #[command(subcommand)]
+    command: ConfigCommands,
+  },
+
+  Registry {
+    #[command(subcommand)]
+    command: RegistryCommands,
+  },
+
+  Cache {
+    #[command(subcommand)]
+    command: CacheCommands,
+  },
+
+  Template {
+    #[command(subcommand)]
+    command: TemplateCommands,
+  },
+
+  Workspace {
+    #[command(subcommand)]
+    command: WorkspaceCommands,
+  },
+}
+
+#[derive(Subcommand)]
+pub enum RegistryCommands {
+  Refresh,
+}
+
+#[derive(Subcommand)]
+pub enum TemplateCommands {
+  Tree { name: String },
+}
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommands {
+  Add {
+    path: PathBuf,
+
+    #[arg(short, long)]
+    template: String,
+
+    #[arg(short = 'C', long, value_name = "DIR", default_value = ".")]
+    workspace: PathBuf,
+
+    #[arg(short = 'm', long)]
+    package_manager: Option<PackageManager>,
+
+    #[arg(long, value_enum)]
+    conflict: Option<FileConflictPolicy>,
+
+    #[arg(long)]
+    no_install: bool,
+  },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+  List,
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+  Verify,
+  Repair,
+  Warm {
+    #[arg(required = true)]
+    names: Vec<String>,
+  },
+  Size,
+  Prune {
+    #[arg(short, long, default_value_t = 30)]
+    days: u64,
+  },
+  Clean {
+    #[arg(short, long)]
+    yes: bool,
+  },
+  Export {
+    bundle: PathBuf,
+    names: Vec<String>,
+  },
+  Import {
+    bundle: PathBuf,
+
+    #[arg(long, value_enum, default_value_t = ConflictPolicy::Newer)]
+    on_conflict: ConflictPolicy,
+  },
 }
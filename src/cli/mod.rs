@@ -1,11 +1,30 @@
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{ArgAction, Parser};
 
 pub mod commands;
 use commands::Commands;
 
+use crate::output::OutputFormat;
+
 #[derive(Parser)]
 #[command(version)]
 pub struct Cli {
+  #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+  pub format: OutputFormat,
+
+  #[arg(long, global = true)]
+  pub offline: bool,
+
+  #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "quiet")]
+  pub verbose: u8,
+
+  #[arg(short, long, global = true)]
+  pub quiet: bool,
+
+  #[arg(long, global = true, value_name = "PATH")]
+  pub log_file: Option<PathBuf>,
+
   #[command(subcommand)]
   pub command: Commands,
 }
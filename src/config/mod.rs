@@ -1 +1,102 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
 
+use anyhow::Result;
+use comfy_table::{Attribute, Cell, Table};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::OxideError, output::Render};
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct OxideConfig {
+  #[serde(rename = "registryTtlHours")]
+  pub registry_ttl_hours: u64,
+  pub offline: bool,
+  pub proxy: Option<String>,
+  #[serde(rename = "noProxy")]
+  pub no_proxy: Option<String>,
+  #[serde(rename = "caCertificates")]
+  pub ca_certificates: Vec<PathBuf>,
+  #[serde(rename = "connectTimeoutSecs")]
+  pub connect_timeout_secs: u64,
+  #[serde(rename = "readTimeoutSecs")]
+  pub read_timeout_secs: u64,
+}
+
+impl Default for OxideConfig {
+  fn default() -> Self {
+    Self {
+      registry_ttl_hours: 24,
+      offline: false,
+      proxy: None,
+      no_proxy: None,
+      ca_certificates: Vec::new(),
+      connect_timeout_secs: 10,
+      read_timeout_secs: 30,
+    }
+  }
+}
+
+impl OxideConfig {
+  pub fn load(config_path: &Path) -> Result<Self> {
+    if !config_path.exists() {
+      return Ok(Self::default());
+    }
+
+    let content = fs::read_to_string(config_path)?;
+    Ok(serde_json::from_str(&content)?)
+  }
+
+  pub fn ensure_online(&self, missing: &[String]) -> Result<()> {
+    if self.offline {
+      return Err(OxideError::Offline(missing.to_vec()).into());
+    }
+    Ok(())
+  }
+
+  fn entries(&self) -> Vec<(String, String)> {
+    let value = serde_json::to_value(self).unwrap_or_default();
+
+    value
+      .as_object()
+      .map(|map| {
+        map
+          .iter()
+          .map(|(key, value)| match value {
+            serde_json::Value::String(s) => (key.clone(), s.clone()),
+            other => (key.clone(), other.to_string()),
+          })
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+}
+
+impl Render for OxideConfig {
+  fn to_table(&self) -> String {
+    let mut table = Table::new();
+
+    table.set_header(vec![
+      Cell::new("Key").add_attribute(Attribute::Bold),
+      Cell::new("Value").add_attribute(Attribute::Bold),
+    ]);
+
+    for (key, value) in self.entries() {
+      table.add_row(vec![Cell::new(key), Cell::new(value)]);
+    }
+
+    table.to_string()
+  }
+
+  fn to_plain(&self) -> String {
+    self
+      .entries()
+      .iter()
+      .map(|(key, value)| format!("{}={}", key, value))
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
@@ -0,0 +1,127 @@
+use regex::Regex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum OxideError {
+  #[error("Template '{0}' not found in registry")]
+  TemplateNotFound(String),
+
+  #[error("Could not reach {url}")]
+  RegistryUnreachable {
+    url: String,
+    #[source]
+    source: reqwest::Error,
+  },
+
+  #[error("GitHub API rate limit exceeded")]
+  RateLimited { reset: Option<String> },
+
+  #[error("Invalid template manifest {path}")]
+  InvalidManifest {
+    path: String,
+    #[source]
+    source: serde_json::Error,
+  },
+
+  #[error("Failed to render {file}{}: {message}", line.map(|l| format!(" (line {})", l)).unwrap_or_default())]
+  Render {
+    file: String,
+    line: Option<usize>,
+    message: String,
+  },
+
+  #[error("Package manager '{0}' is not installed")]
+  PackageManagerMissing(String),
+
+  #[error("'{dir}' already contains files the template would overwrite:\n  - {}", files.join("\n  - "))]
+  FileConflicts { dir: String, files: Vec<String> },
+
+  #[error("Offline mode is enabled, but the following need to be downloaded:\n  - {}", .0.join("\n  - "))]
+  Offline(Vec<String>),
+
+  #[error("Operation cancelled")]
+  Cancelled,
+}
+
+impl OxideError {
+  pub fn exit_code(&self) -> u8 {
+    match self {
+      OxideError::TemplateNotFound(_) => 3,
+      OxideError::RegistryUnreachable { .. } => 4,
+      OxideError::RateLimited { .. } => 5,
+      OxideError::InvalidManifest { .. } => 6,
+      OxideError::Render { .. } => 7,
+      OxideError::PackageManagerMissing(_) => 8,
+      OxideError::FileConflicts { .. } => 9,
+      OxideError::Offline(_) => 10,
+      OxideError::Cancelled => 130,
+    }
+  }
+
+  pub fn hint(&self) -> Option<String> {
+    match self {
+      OxideError::TemplateNotFound(_) => Some(
+        "Run `oxide search <query>` or `oxide list --available` to see available templates"
+          .to_string(),
+      ),
+      OxideError::RegistryUnreachable { .. } => Some(
+        "Check your network connection and proxy settings, or pass --offline to use cached templates"
+          .to_string(),
+      ),
+      OxideError::RateLimited { reset } => Some(match reset {
+        Some(reset) => format!("Try again after {}", reset),
+        None => "Wait a few minutes and try again".to_string(),
+      }),
+      OxideError::InvalidManifest { .. } => Some(
+        "Run `oxide cache repair` to re-download the template, or report it to its author"
+          .to_string(),
+      ),
+      OxideError::Render { file, .. } => Some(format!(
+        "Check the template syntax in {}, then run `oxide cache repair` if the file was edited locally",
+        file
+      )),
+      OxideError::PackageManagerMissing(name) => Some(format!(
+        "Install {} or choose another one with --package-manager",
+        name
+      )),
+      OxideError::FileConflicts { .. } => Some(
+        "Pass --conflict skip|overwrite|prompt|diff to choose how existing files are handled"
+          .to_string(),
+      ),
+      OxideError::Offline(_) => {
+        Some("Run `oxide cache warm <names...>` while online to download them".to_string())
+      }
+      OxideError::Cancelled => None,
+    }
+  }
+
+  pub fn unreachable(source: reqwest::Error) -> Self {
+    OxideError::RegistryUnreachable {
+      url: source
+        .url()
+        .map(|u| u.to_string())
+        .unwrap_or_else(|| "the template registry".to_string()),
+      source,
+    }
+  }
+
+  pub fn render(file: &str, error: tera::Error) -> Self {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(&error);
+    while let Some(cause) = source {
+      message = cause.to_string();
+      source = cause.source();
+    }
+
+    let line = Regex::new(r"--> (\d+):\d+")
+      .unwrap()
+      .captures(&message)
+      .and_then(|c| c[1].parse().ok());
+
+    OxideError::Render {
+      file: file.to_string(),
+      line,
+      message,
+    }
+  }
+}
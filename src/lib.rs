@@ -0,0 +1,16 @@
+pub mod cache;
+pub mod cli;
+pub mod config;
+pub mod error;
+pub mod output;
+pub mod paths;
+pub mod project;
+pub mod prompts;
+pub mod templates;
+pub mod utils;
+
+pub use project::{
+  GeneratedProject, InstallPolicy, ProjectGenerator, TemplateSource,
+  events::{EventSink, GeneratorEvent},
+  workspace::{GeneratedWorkspace, WorkspaceGenerator, WorkspaceMember},
+};
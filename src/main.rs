@@ -1,51 +1,88 @@
-use crate::{
-  cache::{get_installed_templates, remove_template_from_cache},
-  cli::{Cli, commands::Commands},
-  paths::OxidePaths,
-  prompts::{
-    BackendTool, BuildTool, DesktopRuntime, FrontendTool, Language, MetaFramework, MobileTool,
-    PackageManager, ProjectLayer,
-    variables::{
-      ask_backend_framework, ask_desctop_framework, ask_frontend_framework, ask_meta_framework,
-      ask_mobile_framework, ask_project_layer, ask_project_name,
+mod terminal;
+
+use oxide_cli::{
+  cache::{
+    bundle::{export_bundle, import_bundle},
+    get_installed_templates,
+    manage::{clean_cache, get_cache_usage, prune_cache, warm_cache},
+    remove_template_from_cache,
+    verify::{repair_cache, verify_cache},
+  },
+  cli::{
+    Cli,
+    commands::{
+      CacheCommands, Commands, ConfigCommands, RegistryCommands, TemplateCommands,
+      WorkspaceCommands,
     },
   },
-  templates::install::install_template_by_name,
-  utils::{
-    setup::{SetupProjectOptions, setup_project},
-    validate::validate_project_name,
+  config::OxideConfig,
+  output::OutputFormat,
+  paths::OxidePaths,
+  project::conflict::FileConflictPolicy,
+  prompts::{Language, PackageManager},
+  templates::{
+    compose::{resolve_template_name, resolve_template_tree},
+    info::get_template_info,
+    install::install_template_by_name,
+    outdated::get_outdated_templates,
+    registry::{RegistryFetch, get_registry, refresh_registry},
+    search::{RegistryFilter, RegistryListing, filter_registry, search_registry},
   },
+  utils::{cancel::CancelToken, fs::format_size, validate::validate_project_name},
 };
+use std::{path::PathBuf, process::ExitCode};
+
 use anyhow::Result;
 use clap::Parser;
+use inquire::Confirm;
 
-pub mod cache;
-pub mod cli;
-pub mod config;
-pub mod paths;
-pub mod prompts;
-pub mod templates;
-pub mod utils;
+use crate::terminal::{
+  error::report_error,
+  logging,
+  output::render,
+  progress::cli_events,
+  prompts::ask_project_name,
+  setup::{
+    AddMemberOptions, SetupProjectOptions, SetupWorkspaceOptions, add_feature,
+    add_workspace_member, setup_project, setup_workspace,
+  },
+};
 
 pub struct ProjectInitOptions {
   pub name: Option<String>,
-  pub layer: Option<ProjectLayer>,
+  pub layer: Option<String>,
   pub framework: Option<String>,
-  pub build_tool: Option<BuildTool>,
+  pub build_tool: Option<String>,
   pub language: Option<Language>,
   pub platform: Option<String>,
   pub package_manager: Option<PackageManager>,
   pub template_name: Option<String>,
+  pub conflict: Option<FileConflictPolicy>,
+  pub dry_run: bool,
+  pub show: Option<PathBuf>,
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
-  let oxide_paths = OxidePaths::new()?;
+async fn main() -> ExitCode {
+  match run().await {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(e) => report_error(&e),
+  }
+}
 
+async fn run() -> Result<()> {
+  let cli = Cli::parse();
+  logging::init(cli.verbose, cli.quiet, cli.log_file.as_deref())?;
+
+  let oxide_paths = OxidePaths::new()?;
   oxide_paths.ensure_directories()?;
 
-  let cli = Cli::parse();
-  let template_path = oxide_paths.home.join("cache").join("templates");
+  let mut config = OxideConfig::load(&oxide_paths.config)?;
+  config.offline |= cli.offline;
+  let template_path = &oxide_paths.templates;
+
+  let cancel = CancelToken::new();
+  cancel.install_ctrlc_handler()?;
 
   match cli.command {
     Commands::New {
@@ -56,6 +93,15 @@ async fn main() -> Result<()> {
       language,
       platform,
       package_manager,
+      conflict,
+      dry_run,
+      show,
+      workspace,
+      fullstack,
+      frontend,
+      backend,
+      tool,
+      apps,
     } => {
       let project_name = match name {
         Some(n) => n,
@@ -64,6 +110,25 @@ async fn main() -> Result<()> {
 
       validate_project_name(&project_name)?;
 
+      if workspace || fullstack {
+        return setup_workspace(
+          SetupWorkspaceOptions {
+            name: project_name,
+            tool,
+            apps,
+            package_manager,
+            conflict,
+            fullstack,
+            frontend,
+            backend,
+          },
+          &oxide_paths,
+          &config,
+          &cancel,
+        )
+        .await;
+      }
+
       run_project_flow(
         ProjectInitOptions {
           name: Some(project_name),
@@ -74,9 +139,31 @@ async fn main() -> Result<()> {
           platform,
           package_manager,
           template_name: None,
+          conflict,
+          dry_run,
+          show,
         },
         &oxide_paths,
+        &config,
         false,
+        cli.format,
+        &cancel,
+      )
+      .await?
+    }
+    Commands::Add {
+      feature,
+      project,
+      conflict,
+    } => {
+      add_feature(
+        feature,
+        project,
+        conflict,
+        &oxide_paths,
+        &config,
+        cli.format,
+        &cancel,
       )
       .await?
     }
@@ -98,16 +185,158 @@ async fn main() -> Result<()> {
           platform,
           package_manager: None,
           template_name,
+          conflict: None,
+          dry_run: false,
+          show: None,
         },
         &oxide_paths,
+        &config,
         true,
+        cli.format,
+        &cancel,
       )
       .await?
     }
     Commands::Delete { template_name } => {
-      remove_template_from_cache(&template_path, &template_name)?;
+      remove_template_from_cache(template_path, &template_name)?;
     }
-    Commands::Installed {} => get_installed_templates(&template_path)?,
+    Commands::Installed {} => render(&get_installed_templates(template_path)?, cli.format)?,
+    Commands::List {
+      available,
+      layer,
+      framework,
+      language,
+    } => {
+      if available {
+        let registry = get_registry(&oxide_paths.registry, &config).await?;
+        let filter = RegistryFilter {
+          layer,
+          framework,
+          language,
+        };
+        render(
+          &RegistryListing(filter_registry(&registry, &filter)),
+          cli.format,
+        )?;
+      } else {
+        render(&get_installed_templates(template_path)?, cli.format)?;
+      }
+    }
+    Commands::Search {
+      query,
+      layer,
+      framework,
+      language,
+    } => {
+      let registry = get_registry(&oxide_paths.registry, &config).await?;
+      let filter = RegistryFilter {
+        layer,
+        framework,
+        language,
+      };
+      render(
+        &RegistryListing(search_registry(&registry, &query, &filter)),
+        cli.format,
+      )?;
+    }
+    Commands::Info { template_name } => {
+      let info = get_template_info(&oxide_paths, &template_name, &config).await?;
+      render(&info, cli.format)?;
+    }
+    Commands::Outdated {} => {
+      render(
+        &get_outdated_templates(&oxide_paths, &config).await?,
+        cli.format,
+      )?;
+    }
+    Commands::Cache { command } => match command {
+      CacheCommands::Verify => {
+        let report = verify_cache(template_path)?;
+        render(&report, cli.format)?;
+        if !report.is_healthy() {
+          anyhow::bail!("Template cache has problems");
+        }
+      }
+      CacheCommands::Repair => render(
+        &repair_cache(template_path, &config, &cancel, &cli_events()).await?,
+        cli.format,
+      )?,
+      CacheCommands::Warm { names } => render(
+        &warm_cache(&oxide_paths, &names, &config, &cancel, &cli_events()).await?,
+        cli.format,
+      )?,
+      CacheCommands::Export { bundle, names } => {
+        render(&export_bundle(&oxide_paths, &bundle, &names)?, cli.format)?
+      }
+      CacheCommands::Import {
+        bundle,
+        on_conflict,
+      } => render(
+        &import_bundle(&oxide_paths, &bundle, on_conflict)?,
+        cli.format,
+      )?,
+      CacheCommands::Size => render(&get_cache_usage(&oxide_paths)?, cli.format)?,
+      CacheCommands::Prune { days } => render(&prune_cache(&oxide_paths, days)?, cli.format)?,
+      CacheCommands::Clean { yes } => {
+        let confirmed = yes
+          || Confirm::new("Remove all cached templates and the registry?")
+            .with_default(false)
+            .prompt()?;
+
+        if confirmed {
+          let freed = clean_cache(&oxide_paths)?;
+          println!("✓ Cache cleaned, freed {}", format_size(freed));
+        }
+      }
+    },
+    Commands::Config { command } => match command {
+      ConfigCommands::List => render(&config, cli.format)?,
+    },
+    Commands::Registry { command } => match command {
+      RegistryCommands::Refresh => match refresh_registry(&oxide_paths.registry, &config).await? {
+        RegistryFetch::Updated(registry) => {
+          println!(
+            "✓ Registry refreshed ({} templates)",
+            registry.templates().len()
+          )
+        }
+        RegistryFetch::NotModified(_) => println!("✓ Registry is up to date"),
+      },
+    },
+    Commands::Workspace { command } => match command {
+      WorkspaceCommands::Add {
+        path,
+        template,
+        workspace,
+        package_manager,
+        conflict,
+        no_install,
+      } => {
+        add_workspace_member(
+          AddMemberOptions {
+            path,
+            template,
+            workspace,
+            package_manager,
+            conflict,
+            install: !no_install,
+          },
+          &oxide_paths,
+          &config,
+          cli.format,
+          &cancel,
+        )
+        .await?
+      }
+    },
+    Commands::Template { command } => match command {
+      TemplateCommands::Tree { name } => {
+        let path = resolve_template_name(&oxide_paths, &name, &config).await?;
+        let tree =
+          resolve_template_tree(&oxide_paths, &path, &config, &cancel, &cli_events()).await?;
+        render(&tree, cli.format)?;
+      }
+    },
   }
 
   Ok(())
@@ -116,113 +345,34 @@ async fn main() -> Result<()> {
 pub async fn run_project_flow(
   options: ProjectInitOptions,
   oxide_paths: &OxidePaths,
+  config: &OxideConfig,
   is_install: bool,
+  format: OutputFormat,
+  cancel: &CancelToken,
 ) -> Result<()> {
   if let Some(tn) = options.template_name {
-    install_template_by_name(&oxide_paths.home.join("cache").join("templates"), tn).await?;
+    install_template_by_name(oxide_paths, tn, config, cancel, &cli_events()).await?;
   } else {
-    let project_layer = match options.layer {
-      Some(l) => l,
-      None => ask_project_layer()?,
-    };
-
-    match project_layer {
-      ProjectLayer::Frontend => {
-        let framework = match options.framework {
-          Some(f) => f.parse::<FrontendTool>()?,
-          None => ask_frontend_framework()?,
-        };
-        setup_project::<FrontendTool>(
-          SetupProjectOptions {
-            project_name: options.name,
-            framework,
-            build_tool: options.build_tool,
-            language: options.language,
-            platform: options.platform,
-            package_manager: options.package_manager,
-          },
-          oxide_paths,
-          is_install,
-        )
-        .await?
-      }
-      ProjectLayer::Meta => {
-        let framework = match options.framework {
-          Some(f) => f.parse::<MetaFramework>()?,
-          None => ask_meta_framework()?,
-        };
-        setup_project::<MetaFramework>(
-          SetupProjectOptions {
-            project_name: options.name,
-            framework,
-            build_tool: options.build_tool,
-            language: options.language,
-            platform: options.platform,
-            package_manager: options.package_manager,
-          },
-          oxide_paths,
-          is_install,
-        )
-        .await?;
-      }
-      ProjectLayer::Backend => {
-        let framework = match options.framework {
-          Some(f) => f.parse::<BackendTool>()?,
-          None => ask_backend_framework()?,
-        };
-        setup_project::<BackendTool>(
-          SetupProjectOptions {
-            project_name: options.name,
-            framework,
-            build_tool: options.build_tool,
-            language: options.language,
-            platform: options.platform,
-            package_manager: options.package_manager,
-          },
-          oxide_paths,
-          is_install,
-        )
-        .await?;
-      }
-      ProjectLayer::Desktop => {
-        let framework = match options.framework {
-          Some(f) => f.parse::<DesktopRuntime>()?,
-          None => ask_desctop_framework()?,
-        };
-        setup_project::<DesktopRuntime>(
-          SetupProjectOptions {
-            project_name: options.name,
-            framework,
-            build_tool: options.build_tool,
-            language: options.language,
-            platform: options.platform,
-            package_manager: options.package_manager,
-          },
-          oxide_paths,
-          is_install,
-        )
-        .await?;
-      }
-      ProjectLayer::Mobile => {
-        let framework = match options.framework {
-          Some(f) => f.parse::<MobileTool>()?,
-          None => ask_mobile_framework()?,
-        };
-        setup_project::<MobileTool>(
-          SetupProjectOptions {
-            project_name: options.name,
-            framework,
-            build_tool: options.build_tool,
-            language: options.language,
-            platform: options.platform,
-            package_manager: options.package_manager,
-          },
-          oxide_paths,
-          is_install,
-        )
-        .await?;
-      }
-    };
+    setup_project(
+      SetupProjectOptions {
+        project_name: options.name,
+        layer: options.layer,
+        framework: options.framework,
+        build_tool: options.build_tool,
+        language: options.language,
+        platform: options.platform,
+        package_manager: options.package_manager,
+        conflict: options.conflict,
+        dry_run: options.dry_run,
+        show: options.show,
+      },
+      oxide_paths,
+      config,
+      is_install,
+      format,
+      cancel,
+    )
+    .await?;
   }
   Ok(())
 }
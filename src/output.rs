@@ -0,0 +1,15 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+  #[default]
+  Table,
+  Json,
+  Plain,
+}
+
+pub trait Render: Serialize {
+  fn to_table(&self) -> String;
+  fn to_plain(&self) -> String;
+}
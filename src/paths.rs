@@ -1,7 +1,12 @@
-use std::{fs, path::PathBuf};
+use std::{
+  env, fs,
+  path::{Path, PathBuf},
+};
 
 use anyhow::Result;
+use log::info;
 
+#[derive(Clone)]
 pub struct OxidePaths {
   pub home: PathBuf,
   pub config: PathBuf,
@@ -12,18 +17,103 @@ pub struct OxidePaths {
 
 impl OxidePaths {
   pub fn new() -> Result<Self> {
+    if let Some(oxide_home) = env::var_os("OXIDE_HOME").filter(|v| !v.is_empty()) {
+      let paths = Self::from_home(PathBuf::from(oxide_home));
+      paths.migrate_registry(&paths.templates)?;
+      return Ok(paths);
+    }
+
     let home_dir =
       dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
+    let legacy_home = home_dir.join(".oxide");
+
+    if cfg!(target_os = "linux")
+      && let (Some(config_dir), Some(cache_dir)) = (dirs::config_dir(), dirs::cache_dir())
+    {
+      let paths = Self::from_dirs(config_dir.join("oxide"), cache_dir.join("oxide"));
+      paths.migrate_from(&legacy_home)?;
+      return Ok(paths);
+    }
 
-    let oxide_home = home_dir.join(".oxide");
+    let paths = Self::from_home(legacy_home);
+    paths.migrate_registry(&paths.templates)?;
+    Ok(paths)
+  }
 
-    Ok(Self {
+  fn from_home(oxide_home: PathBuf) -> Self {
+    Self {
       home: oxide_home.clone(),
       config: oxide_home.join("config.json"),
       registry: oxide_home.join("oxide-registry.json"),
       cache: oxide_home.join("cache"),
       templates: oxide_home.join("cache").join("templates"),
-    })
+    }
+  }
+
+  fn from_dirs(config_home: PathBuf, cache_home: PathBuf) -> Self {
+    Self {
+      home: config_home.clone(),
+      config: config_home.join("config.json"),
+      registry: cache_home.join("oxide-registry.json"),
+      cache: cache_home.clone(),
+      templates: cache_home.join("templates"),
+    }
+  }
+
+  fn migrate_from(&self, legacy_home: &Path) -> Result<()> {
+    if !legacy_home.is_dir() || self.home.exists() || self.cache.exists() {
+      return Ok(());
+    }
+
+    let legacy = Self::from_home(legacy_home.to_path_buf());
+    self.ensure_directories()?;
+
+    move_path(&legacy.config, &self.config)?;
+    self.migrate_registry(&legacy.templates)?;
+
+    if legacy.templates.exists() {
+      fs::remove_dir(&self.templates)?;
+      move_path(&legacy.templates, &self.templates)?;
+      fs::create_dir_all(&self.templates)?;
+    }
+
+    let _ = fs::remove_dir(&legacy.cache);
+    let _ = fs::remove_dir(legacy_home);
+
+    info!(
+      "✓ Migrated {} to {} and {}",
+      legacy_home.display(),
+      self.home.display(),
+      self.cache.display()
+    );
+
+    Ok(())
+  }
+
+  fn migrate_registry(&self, legacy_templates: &Path) -> Result<()> {
+    let files = [
+      (
+        legacy_templates.join("oxide-registry.json"),
+        self.registry.clone(),
+      ),
+      (
+        legacy_templates.join("oxide-registry.meta.json"),
+        self.registry.with_extension("meta.json"),
+      ),
+    ];
+
+    for (legacy, current) in files {
+      if !legacy.exists() {
+        continue;
+      }
+      if current.exists() {
+        fs::remove_file(&legacy)?;
+      } else {
+        move_path(&legacy, &current)?;
+      }
+    }
+
+    Ok(())
   }
 
   pub fn ensure_directories(&self) -> Result<()> {
@@ -33,3 +123,25 @@ impl OxidePaths {
     Ok(())
   }
 }
+
+fn move_path(from: &Path, to: &Path) -> Result<()> {
+  if !from.exists() {
+    return Ok(());
+  }
+
+  if fs::rename(from, to).is_ok() {
+    return Ok(());
+  }
+
+  if from.is_dir() {
+    let options = fs_extra::dir::CopyOptions::new().content_only(true);
+    fs::create_dir_all(to)?;
+    fs_extra::dir::copy(from, to, &options)?;
+    fs::remove_dir_all(from)?;
+  } else {
+    fs::copy(from, to)?;
+    fs::remove_file(from)?;
+  }
+
+  Ok(())
+}
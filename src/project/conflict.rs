@@ -0,0 +1,157 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use anyhow::{Result, bail};
+use clap::ValueEnum;
+use similar::TextDiff;
+
+use crate::{
+  error::OxideError,
+  project::events::{EventSink, GeneratorEvent},
+  templates::generator::RenderedFile,
+  utils::fs::relative_key,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum FileConflictPolicy {
+  Skip,
+  Overwrite,
+  Prompt,
+  Diff,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConflictResolution {
+  Overwrite,
+  Skip,
+}
+
+pub struct FileConflict<'a> {
+  pub path: PathBuf,
+  pub existing: &'a [u8],
+  pub generated: &'a [u8],
+}
+
+pub type ConflictHandler = Arc<dyn Fn(&FileConflict) -> Result<ConflictResolution> + Send + Sync>;
+
+#[derive(Default)]
+pub struct WriteSummary {
+  pub written: Vec<PathBuf>,
+  pub overwritten: Vec<PathBuf>,
+  pub skipped: Vec<PathBuf>,
+  pub unchanged: Vec<PathBuf>,
+}
+
+impl FileConflict<'_> {
+  pub fn diff(&self) -> String {
+    let existing = String::from_utf8_lossy(self.existing);
+    let generated = String::from_utf8_lossy(self.generated);
+    let name = relative_key(&self.path);
+
+    TextDiff::from_lines(existing.as_ref(), generated.as_ref())
+      .unified_diff()
+      .header(&format!("a/{}", name), &format!("b/{}", name))
+      .to_string()
+  }
+}
+
+pub fn find_conflicts(output_path: &Path, files: &[RenderedFile]) -> Result<Vec<PathBuf>> {
+  let mut conflicts = Vec::new();
+
+  for file in files {
+    let target = output_path.join(&file.path);
+    if target.exists() && fs::read(&target)? != file.contents {
+      conflicts.push(file.path.clone());
+    }
+  }
+
+  Ok(conflicts)
+}
+
+pub fn write_files(
+  output_path: &Path,
+  files: &[RenderedFile],
+  policy: Option<FileConflictPolicy>,
+  handler: Option<&ConflictHandler>,
+  events: &EventSink,
+) -> Result<WriteSummary> {
+  let conflicts = find_conflicts(output_path, files)?;
+
+  if !conflicts.is_empty() {
+    match policy {
+      None => {
+        return Err(
+          OxideError::FileConflicts {
+            dir: output_path.display().to_string(),
+            files: conflicts.iter().map(|p| relative_key(p)).collect(),
+          }
+          .into(),
+        );
+      }
+      Some(FileConflictPolicy::Prompt) if handler.is_none() => {
+        bail!("Prompting for conflicts requires a conflict handler")
+      }
+      _ => {}
+    }
+  }
+
+  fs::create_dir_all(output_path)?;
+  let mut summary = WriteSummary::default();
+
+  for file in files {
+    let target = output_path.join(&file.path);
+
+    if target.exists() {
+      let existing = fs::read(&target)?;
+      if existing == file.contents {
+        summary.unchanged.push(file.path.clone());
+        continue;
+      }
+
+      let conflict = FileConflict {
+        path: file.path.clone(),
+        existing: &existing,
+        generated: &file.contents,
+      };
+
+      let resolution = match policy {
+        Some(FileConflictPolicy::Overwrite) => ConflictResolution::Overwrite,
+        Some(FileConflictPolicy::Prompt) => match handler {
+          Some(handler) => handler(&conflict)?,
+          None => ConflictResolution::Skip,
+        },
+        Some(FileConflictPolicy::Diff) => {
+          events.emit(GeneratorEvent::ConflictDiff {
+            path: target.clone(),
+            diff: conflict.diff(),
+          });
+          ConflictResolution::Skip
+        }
+        Some(FileConflictPolicy::Skip) | None => ConflictResolution::Skip,
+      };
+
+      if resolution == ConflictResolution::Skip {
+        events.emit(GeneratorEvent::FileSkipped { path: target });
+        summary.skipped.push(file.path.clone());
+        continue;
+      }
+
+      fs::write(&target, &file.contents)?;
+      events.emit(GeneratorEvent::FileOverwritten { path: target });
+      summary.overwritten.push(file.path.clone());
+      continue;
+    }
+
+    if let Some(parent) = target.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    fs::write(&target, &file.contents)?;
+    events.emit(GeneratorEvent::FileWritten { path: target });
+    summary.written.push(file.path.clone());
+  }
+
+  Ok(summary)
+}
@@ -0,0 +1,46 @@
+use std::{path::PathBuf, sync::Arc};
+
+use crate::prompts::PackageManager;
+
+pub enum GeneratorEvent {
+  TemplateResolved { path: PathBuf },
+  TemplateCached { path: PathBuf },
+  DownloadStarted { path: PathBuf },
+  DownloadQueued { files: u64, bytes: u64 },
+  FileDownloaded { path: PathBuf, bytes: u64 },
+  DownloadFinished,
+  RenderStarted { files: usize },
+  FileRendered { path: PathBuf },
+  RenderFinished,
+  FileWritten { path: PathBuf },
+  FileSkipped { path: PathBuf },
+  FileOverwritten { path: PathBuf },
+  FilePatched { path: PathBuf },
+  ConflictDiff { path: PathBuf, diff: String },
+  InstallStarted { package_manager: PackageManager },
+  InstallOutput { line: String },
+  InstallFinished { success: bool },
+  CleanedUp { path: PathBuf },
+  MemberStarted { path: PathBuf },
+}
+
+type EventHandler = Arc<dyn Fn(&GeneratorEvent) + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub struct EventSink {
+  handler: Option<EventHandler>,
+}
+
+impl EventSink {
+  pub fn new(handler: impl Fn(&GeneratorEvent) + Send + Sync + 'static) -> Self {
+    Self {
+      handler: Some(Arc::new(handler)),
+    }
+  }
+
+  pub fn emit(&self, event: GeneratorEvent) {
+    if let Some(handler) = &self.handler {
+      handler(&event);
+    }
+  }
+}
@@ -0,0 +1,266 @@
+use std::{
+  collections::BTreeMap,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use anyhow::{Result, bail};
+use chrono::Utc;
+use comfy_table::{Attribute, Cell, Table};
+use log::debug;
+use serde::Serialize;
+
+use crate::{
+  config::OxideConfig,
+  error::OxideError,
+  output::Render,
+  paths::OxidePaths,
+  project::{
+    conflict::{
+      ConflictHandler, ConflictResolution, FileConflict, FileConflictPolicy, write_files,
+    },
+    dir_name,
+    events::{EventSink, GeneratorEvent},
+    metadata::{FeatureRecord, ProjectMetadata},
+    patch::{apply_patches, split_patches},
+    template_defaults,
+  },
+  templates::{
+    generator::extract_template,
+    loader::get_files,
+    registry::{TemplateKind, get_registry},
+  },
+  utils::{cancel::CancelToken, fs::relative_key},
+};
+
+pub struct FeatureApplier {
+  feature: String,
+  project_dir: PathBuf,
+  answers: BTreeMap<String, String>,
+  conflict_policy: Option<FileConflictPolicy>,
+  conflict_handler: Option<ConflictHandler>,
+  paths: Option<OxidePaths>,
+  config: OxideConfig,
+  cancel: CancelToken,
+  events: EventSink,
+}
+
+#[derive(Serialize)]
+pub struct AppliedFeature {
+  pub name: String,
+  pub template: String,
+  pub created: Vec<String>,
+  pub patched: Vec<String>,
+  pub overwritten: Vec<String>,
+  pub skipped: Vec<String>,
+  pub unchanged: Vec<String>,
+}
+
+impl FeatureApplier {
+  pub fn new(feature: impl Into<String>, project_dir: impl Into<PathBuf>) -> Self {
+    Self {
+      feature: feature.into(),
+      project_dir: project_dir.into(),
+      answers: BTreeMap::new(),
+      conflict_policy: None,
+      conflict_handler: None,
+      paths: None,
+      config: OxideConfig::default(),
+      cancel: CancelToken::new(),
+      events: EventSink::default(),
+    }
+  }
+
+  pub fn answer(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.answers.insert(name.into(), value.into());
+    self
+  }
+
+  pub fn conflict_policy(mut self, policy: Option<FileConflictPolicy>) -> Self {
+    self.conflict_policy = policy;
+    self
+  }
+
+  pub fn on_conflict(
+    mut self,
+    handler: impl Fn(&FileConflict) -> Result<ConflictResolution> + Send + Sync + 'static,
+  ) -> Self {
+    self.conflict_handler = Some(Arc::new(handler));
+    self
+  }
+
+  pub fn paths(mut self, paths: OxidePaths) -> Self {
+    self.paths = Some(paths);
+    self
+  }
+
+  pub fn config(mut self, config: OxideConfig) -> Self {
+    self.config = config;
+    self
+  }
+
+  pub fn cancel_token(mut self, cancel: CancelToken) -> Self {
+    self.cancel = cancel;
+    self
+  }
+
+  pub fn events(mut self, events: EventSink) -> Self {
+    self.events = events;
+    self
+  }
+
+  pub async fn apply(self) -> Result<AppliedFeature> {
+    let paths = match &self.paths {
+      Some(paths) => paths.clone(),
+      None => OxidePaths::new()?,
+    };
+    paths.ensure_directories()?;
+
+    if !self.project_dir.is_dir() {
+      bail!("{} is not a directory", self.project_dir.display());
+    }
+
+    let mut metadata = match ProjectMetadata::load(&self.project_dir)? {
+      Some(metadata) => metadata,
+      None => ProjectMetadata {
+        name: dir_name(&self.project_dir)?,
+        ..Default::default()
+      },
+    };
+
+    let registry = get_registry(&paths.registry, &self.config).await?;
+    let entry = registry
+      .iter()
+      .find(|t| t.name == self.feature)
+      .ok_or_else(|| OxideError::TemplateNotFound(self.feature.clone()))?;
+    if entry.kind != TemplateKind::Feature {
+      bail!(
+        "'{}' is a project template, not a feature. Use `oxide new` instead",
+        self.feature
+      );
+    }
+
+    let template = PathBuf::from(&entry.path);
+    debug!(
+      "Resolved feature {} to {}",
+      self.feature,
+      template.display()
+    );
+    self.events.emit(GeneratorEvent::TemplateResolved {
+      path: template.clone(),
+    });
+
+    let loaded = get_files(&template, &paths, &self.config, &self.cancel, &self.events).await?;
+    let files: Vec<_> = loaded
+      .files
+      .into_iter()
+      .filter(|file| file.path != Path::new("oxide.template.json"))
+      .collect();
+
+    let mut variables = template_defaults(&paths.templates, &loaded.tree);
+    variables.extend(self.answers.clone());
+
+    let rendered = extract_template(
+      &files,
+      &self.project_dir,
+      &metadata.name,
+      &variables,
+      &self.cancel,
+      &self.events,
+    )?;
+    self.cancel.check()?;
+
+    let (plain, pending) = split_patches(rendered)?;
+
+    let summary = write_files(
+      &self.project_dir,
+      &plain,
+      self.conflict_policy,
+      self.conflict_handler.as_ref(),
+      &self.events,
+    )?;
+    let patches = apply_patches(&self.project_dir, &pending, &self.events)?;
+
+    let keys = |paths: &[PathBuf]| paths.iter().map(|p| relative_key(p)).collect::<Vec<_>>();
+    let mut unchanged = keys(&summary.unchanged);
+    unchanged.extend(keys(&patches.unchanged));
+
+    let applied = AppliedFeature {
+      name: self.feature.clone(),
+      template: relative_key(&template),
+      created: keys(&summary.written),
+      patched: keys(&patches.patched),
+      overwritten: keys(&summary.overwritten),
+      skipped: keys(&summary.skipped),
+      unchanged,
+    };
+
+    let mut record_files: Vec<String> = plain
+      .iter()
+      .map(|f| relative_key(&f.path))
+      .chain(pending.iter().map(|p| relative_key(&p.target)))
+      .collect();
+    record_files.sort();
+    record_files.dedup();
+
+    if !applied.is_unchanged() || metadata.feature(&self.feature).is_none() {
+      metadata.record_feature(FeatureRecord {
+        name: self.feature.clone(),
+        version: entry.version.clone(),
+        applied_at: Utc::now().to_rfc3339(),
+        files: record_files,
+      });
+      metadata.save(&self.project_dir)?;
+    }
+
+    Ok(applied)
+  }
+}
+
+impl AppliedFeature {
+  pub fn is_unchanged(&self) -> bool {
+    self.created.is_empty() && self.patched.is_empty() && self.overwritten.is_empty()
+  }
+}
+
+impl Render for AppliedFeature {
+  fn to_table(&self) -> String {
+    if self.is_unchanged() && self.skipped.is_empty() {
+      return format!("✓ {} is already applied, nothing to change", self.name);
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec![
+      Cell::new("File").add_attribute(Attribute::Bold),
+      Cell::new("Change").add_attribute(Attribute::Bold),
+    ]);
+
+    for (files, change) in [
+      (&self.created, "created"),
+      (&self.patched, "patched"),
+      (&self.overwritten, "overwritten"),
+      (&self.skipped, "kept existing"),
+      (&self.unchanged, "unchanged"),
+    ] {
+      for file in files {
+        table.add_row(vec![Cell::new(file), Cell::new(change)]);
+      }
+    }
+
+    format!("✓ Applied {}\n{}", self.name, table)
+  }
+
+  fn to_plain(&self) -> String {
+    [
+      (&self.created, "created"),
+      (&self.patched, "patched"),
+      (&self.overwritten, "overwritten"),
+      (&self.skipped, "skipped"),
+      (&self.unchanged, "unchanged"),
+    ]
+    .into_iter()
+    .flat_map(|(files, change)| files.iter().map(move |f| format!("{}\t{}", change, f)))
+    .collect::<Vec<_>>()
+    .join("\n")
+  }
+}
@@ -0,0 +1,443 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use log::warn;
+use regex::Regex;
+use serde_json::{Map, Value, json};
+
+use crate::{
+  project::{
+    events::{EventSink, GeneratorEvent},
+    patch::{Patch, patch_document},
+  },
+  prompts::PackageManager,
+  templates::generator::RenderedFile,
+  utils::fs::relative_key,
+};
+
+pub const API_PORT: u16 = 3001;
+pub const TYPES_PATH: &str = "packages/types";
+
+pub struct FullstackSummary {
+  pub web: PathBuf,
+  pub api: PathBuf,
+  pub types: String,
+  pub proxy: Option<PathBuf>,
+}
+
+struct ProxyTarget {
+  files: &'static [&'static str],
+  anchor: &'static str,
+  existing: &'static str,
+  block: Option<&'static str>,
+  snippet: fn(&str) -> String,
+}
+
+enum ProxyEdit {
+  Patched(String),
+  AlreadyConfigured,
+  NoConfigObject,
+}
+
+const PROXY_TARGETS: &[ProxyTarget] = &[
+  ProxyTarget {
+    files: &[
+      "vite.config.ts",
+      "vite.config.mts",
+      "vite.config.js",
+      "vite.config.mjs",
+      "farm.config.ts",
+      "farm.config.js",
+      "rsbuild.config.ts",
+      "rsbuild.config.mjs",
+      "rsbuild.config.js",
+    ],
+    anchor: r"defineConfig\(\s*(\([^)]*\)\s*=>\s*\(\s*)?\{",
+    existing: r"\bproxy\s*:",
+    block: Some("server"),
+    snippet: |target| {
+      format!(
+        "proxy: {{\n  '/api': {{ target: '{}', changeOrigin: true }},\n}},",
+        target
+      )
+    },
+  },
+  ProxyTarget {
+    files: &["nuxt.config.ts", "nuxt.config.js"],
+    anchor: r"defineNuxtConfig\(\s*\{",
+    existing: r"\bdevProxy\s*:",
+    block: Some("nitro"),
+    snippet: |target| {
+      format!(
+        "devProxy: {{\n  '/api': {{ target: '{}/api', changeOrigin: true }},\n}},",
+        target
+      )
+    },
+  },
+  ProxyTarget {
+    files: &["next.config.ts", "next.config.mjs", "next.config.js"],
+    anchor: r"nextConfig(\s*:\s*NextConfig)?\s*=\s*\{",
+    existing: r"\brewrites\s*(\(|:)",
+    block: None,
+    snippet: |target| {
+      format!(
+        "async rewrites() {{\n  return [{{ source: '/api/:path*', destination: '{}/api/:path*' }}];\n}},",
+        target
+      )
+    },
+  },
+];
+
+pub(crate) fn types_package_name(workspace_name: &str) -> String {
+  format!("@{}/types", workspace_name.to_lowercase())
+}
+
+pub(crate) fn types_files(workspace_name: &str) -> Result<Vec<RenderedFile>> {
+  let file = |path: &str, contents: String| RenderedFile {
+    path: Path::new(TYPES_PATH).join(path),
+    contents: contents.into_bytes(),
+    rendered: true,
+  };
+
+  let package = json!({
+    "name": types_package_name(workspace_name),
+    "version": "0.0.0",
+    "private": true,
+    "main": "./src/index.ts",
+    "types": "./src/index.ts"
+  });
+  let tsconfig = json!({
+    "compilerOptions": {
+      "target": "ES2020",
+      "module": "ESNext",
+      "moduleResolution": "Bundler",
+      "strict": true,
+      "declaration": true,
+      "composite": true,
+      "skipLibCheck": true
+    },
+    "include": ["src"]
+  });
+
+  Ok(vec![
+    file(
+      "package.json",
+      format!("{}\n", serde_json::to_string_pretty(&package)?),
+    ),
+    file(
+      "tsconfig.json",
+      format!("{}\n", serde_json::to_string_pretty(&tsconfig)?),
+    ),
+    file(
+      "src/index.ts",
+      "export interface HealthResponse {\n  status: 'ok';\n  timestamp: string;\n}\n".to_string(),
+    ),
+  ])
+}
+
+pub(crate) fn wire(
+  root: &Path,
+  name: &str,
+  package_manager: PackageManager,
+  web: &Path,
+  api: &Path,
+  events: &EventSink,
+) -> Result<FullstackSummary> {
+  let types = types_package_name(name);
+  let version = match package_manager {
+    PackageManager::NPM => "*",
+    _ => "workspace:*",
+  };
+
+  for member in [web, api] {
+    let mut dependencies = Map::new();
+    dependencies.insert(
+      "dependencies".to_string(),
+      json!({ types.as_str(): version }),
+    );
+    patch_json(
+      &root.join(member).join("package.json"),
+      Patch {
+        dependencies,
+        ..Default::default()
+      },
+      events,
+    )?;
+  }
+
+  let target = format!("http://localhost:{}", API_PORT);
+  let proxy = configure_proxy(&root.join(web), &target, events)?;
+
+  let run = |member: &Path, env: &str, preferred: &[&str]| -> Result<String> {
+    let script = dev_script(&root.join(member), preferred)?;
+    Ok(format!(
+      "cd {} && {}{} run {}",
+      relative_key(member),
+      env,
+      package_manager,
+      script
+    ))
+  };
+  let web_script = run(web, "", &["dev", "start"])?;
+  let api_script = run(
+    api,
+    &format!("cross-env PORT={} ", API_PORT),
+    &["start:dev", "dev", "start"],
+  )?;
+
+  let mut dependencies = Map::new();
+  dependencies.insert(
+    "devDependencies".to_string(),
+    json!({ "concurrently": "^9.1.0", "cross-env": "^7.0.3" }),
+  );
+  patch_json(
+    &root.join("package.json"),
+    Patch {
+      merge: Some(json!({
+        "scripts": {
+          "dev": format!(
+            "concurrently -k -n web,api -c cyan,magenta \"{pm} run dev:web\" \"{pm} run dev:api\"",
+            pm = package_manager
+          ),
+          "dev:web": web_script,
+          "dev:api": api_script
+        }
+      })),
+      dependencies,
+      ..Default::default()
+    },
+    events,
+  )?;
+
+  Ok(FullstackSummary {
+    web: web.to_path_buf(),
+    api: api.to_path_buf(),
+    types,
+    proxy,
+  })
+}
+
+pub(crate) fn patch_json(path: &Path, patch: Patch, events: &EventSink) -> Result<()> {
+  let existing = path.exists().then(|| fs::read(path)).transpose()?;
+  if let Some(patched) = patch_document(path, existing.as_deref(), &[&patch])? {
+    fs::write(path, patched)?;
+    events.emit(GeneratorEvent::FilePatched {
+      path: path.to_path_buf(),
+    });
+  }
+  Ok(())
+}
+
+fn dev_script(member_dir: &Path, preferred: &[&str]) -> Result<String> {
+  let path = member_dir.join("package.json");
+  let scripts = if path.exists() {
+    serde_json::from_slice::<Value>(&fs::read(&path)?)?
+      .get("scripts")
+      .cloned()
+      .unwrap_or_default()
+  } else {
+    Value::Null
+  };
+
+  let script = preferred
+    .iter()
+    .find(|name| scripts.get(**name).is_some())
+    .unwrap_or(&preferred[0]);
+  Ok(script.to_string())
+}
+
+fn configure_proxy(web_dir: &Path, target: &str, events: &EventSink) -> Result<Option<PathBuf>> {
+  if web_dir.join("angular.json").exists() {
+    return configure_angular_proxy(web_dir, target, events);
+  }
+
+  for proxy in PROXY_TARGETS {
+    let Some(file) = proxy
+      .files
+      .iter()
+      .map(|f| web_dir.join(f))
+      .find(|f| f.exists())
+    else {
+      continue;
+    };
+
+    let content = fs::read_to_string(&file)?;
+    let content = match insert_proxy(proxy, &content, target)? {
+      ProxyEdit::Patched(content) => content,
+      ProxyEdit::AlreadyConfigured => {
+        warn!(
+          "⚠ {} already configures a proxy, add /api → {} yourself",
+          file.display(),
+          target
+        );
+        return Ok(None);
+      }
+      ProxyEdit::NoConfigObject => {
+        warn!(
+          "⚠ No config object found in {}, the /api proxy was not configured",
+          file.display()
+        );
+        return Ok(None);
+      }
+    };
+
+    fs::write(&file, content)?;
+    events.emit(GeneratorEvent::FilePatched { path: file.clone() });
+    return Ok(Some(file));
+  }
+
+  Ok(None)
+}
+
+fn insert_proxy(proxy: &ProxyTarget, content: &str, target: &str) -> Result<ProxyEdit> {
+  let Some(found) = Regex::new(proxy.anchor)?.find(content) else {
+    return Ok(ProxyEdit::NoConfigObject);
+  };
+
+  let config = &content[found.end()..];
+  if Regex::new(proxy.existing)?.is_match(config) {
+    return Ok(ProxyEdit::AlreadyConfigured);
+  }
+
+  let snippet = (proxy.snippet)(target);
+  let existing_block = match proxy.block {
+    Some(block) => Regex::new(&format!(r"\b{}\s*:\s*\{{", block))?.find(config),
+    None => None,
+  };
+  let (position, insert) = match (proxy.block, existing_block) {
+    (_, Some(existing)) => (found.end() + existing.end(), indent(&snippet, 2)),
+    (Some(block), None) => (
+      found.end(),
+      indent(&format!("{}: {{\n{}\n}},", block, indent(&snippet, 1)), 1),
+    ),
+    (None, None) => (found.end(), indent(&snippet, 1)),
+  };
+
+  let mut content = content.to_string();
+  content.insert_str(position, &format!("\n{}", insert));
+  Ok(ProxyEdit::Patched(content))
+}
+
+fn indent(text: &str, level: usize) -> String {
+  text
+    .lines()
+    .map(|line| format!("{}{}", "  ".repeat(level), line))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn configure_angular_proxy(
+  web_dir: &Path,
+  target: &str,
+  events: &EventSink,
+) -> Result<Option<PathBuf>> {
+  let angular_path = web_dir.join("angular.json");
+  let mut angular: Value = serde_json::from_slice(&fs::read(&angular_path)?)?;
+
+  let mut configured = false;
+  if let Some(projects) = angular.get_mut("projects").and_then(Value::as_object_mut) {
+    for project in projects.values_mut() {
+      if let Some(Value::Object(options)) = project.pointer_mut("/architect/serve/options") {
+        options.insert("proxyConfig".to_string(), json!("proxy.conf.json"));
+        configured = true;
+      } else if let Some(Value::Object(serve)) = project.pointer_mut("/architect/serve") {
+        serve.insert(
+          "options".to_string(),
+          json!({ "proxyConfig": "proxy.conf.json" }),
+        );
+        configured = true;
+      }
+    }
+  }
+  if !configured {
+    warn!(
+      "⚠ No serve target found in {}, the /api proxy was not configured",
+      angular_path.display()
+    );
+    return Ok(None);
+  }
+
+  fs::write(
+    &angular_path,
+    format!("{}\n", serde_json::to_string_pretty(&angular)?),
+  )?;
+  events.emit(GeneratorEvent::FilePatched {
+    path: angular_path.clone(),
+  });
+
+  let proxy_path = web_dir.join("proxy.conf.json");
+  let proxy = json!({ "/api": { "target": target, "secure": false, "changeOrigin": true } });
+  fs::write(
+    &proxy_path,
+    format!("{}\n", serde_json::to_string_pretty(&proxy)?),
+  )?;
+  events.emit(GeneratorEvent::FileWritten {
+    path: proxy_path.clone(),
+  });
+
+  Ok(Some(angular_path))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TARGET: &str = "http://localhost:3001";
+
+  fn patched(proxy: &ProxyTarget, content: &str) -> String {
+    match insert_proxy(proxy, content, TARGET).unwrap() {
+      ProxyEdit::Patched(content) => content,
+      _ => panic!("expected the proxy to be inserted"),
+    }
+  }
+
+  #[test]
+  fn adds_server_block_to_vite_config() {
+    let content = "export default defineConfig({\n  plugins: [react()],\n})\n";
+
+    assert_eq!(
+      patched(&PROXY_TARGETS[0], content),
+      "export default defineConfig({\n  server: {\n    proxy: {\n      '/api': { target: 'http://localhost:3001', changeOrigin: true },\n    },\n  },\n  plugins: [react()],\n})\n"
+    );
+  }
+
+  #[test]
+  fn merges_into_existing_server_block() {
+    let content =
+      "export default defineConfig({\n  plugins: [react()],\n  server: { port: 3000 },\n})\n";
+    let content = patched(&PROXY_TARGETS[0], content);
+
+    assert_eq!(content.matches("server:").count(), 1);
+    assert!(content.contains(
+      "server: {\n    proxy: {\n      '/api': { target: 'http://localhost:3001', changeOrigin: true },\n    }, port: 3000 },"
+    ));
+  }
+
+  #[test]
+  fn merges_into_existing_nitro_block() {
+    let content = "export default defineNuxtConfig({\n  nitro: {\n    preset: 'node',\n  },\n})\n";
+    let content = patched(&PROXY_TARGETS[1], content);
+
+    assert_eq!(content.matches("nitro:").count(), 1);
+    assert!(content.contains("nitro: {\n    devProxy: {"));
+    assert!(content.contains("preset: 'node'"));
+  }
+
+  #[test]
+  fn skips_configs_with_a_proxy() {
+    let content =
+      "// proxy set up below\nexport default defineConfig({\n  server: { proxy: {} },\n})\n";
+
+    assert!(matches!(
+      insert_proxy(&PROXY_TARGETS[0], content, TARGET).unwrap(),
+      ProxyEdit::AlreadyConfigured
+    ));
+    assert!(matches!(
+      insert_proxy(&PROXY_TARGETS[0], "module.exports = {}", TARGET).unwrap(),
+      ProxyEdit::NoConfigObject
+    ));
+  }
+}
@@ -0,0 +1,631 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use anyhow::{Result, anyhow, bail};
+use comfy_table::{Attribute, Cell, Table};
+use log::{debug, warn};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+
+use crate::{
+  config::OxideConfig,
+  error::OxideError,
+  output::Render,
+  paths::OxidePaths,
+  project::{
+    InstallPolicy, ProjectGenerator, TemplateSource,
+    conflict::{ConflictHandler, ConflictResolution, FileConflict, FileConflictPolicy},
+    dir_name,
+    events::{EventSink, GeneratorEvent},
+    install_dependencies,
+    metadata::{MemberRecord, PROJECT_METADATA, ProjectMetadata, WorkspaceRecord},
+    patch::{Patch, patch_document},
+    workspace::{add_dev_script, check_member_path, split_member_path},
+  },
+  prompts::PackageManager,
+  utils::{cancel::CancelToken, fs::relative_key},
+};
+
+const PNPM_WORKSPACE: &str = "pnpm-workspace.yaml";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkspaceConfig {
+  Pnpm,
+  PackageJson { nested: bool },
+  Oxide,
+}
+
+pub struct WorkspaceLayout {
+  pub root: PathBuf,
+  pub config: WorkspaceConfig,
+  pub packages: Vec<String>,
+}
+
+pub struct MemberAdder {
+  workspace_dir: PathBuf,
+  path: PathBuf,
+  source: TemplateSource,
+  package_manager: Option<PackageManager>,
+  install: bool,
+  conflict_policy: Option<FileConflictPolicy>,
+  conflict_handler: Option<ConflictHandler>,
+  paths: Option<OxidePaths>,
+  config: OxideConfig,
+  cancel: CancelToken,
+  events: EventSink,
+}
+
+#[derive(Serialize)]
+pub struct AddedMember {
+  pub root: String,
+  pub path: String,
+  pub template: String,
+  pub files: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub registered: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub references: Option<String>,
+  #[serde(rename = "packageManager")]
+  pub package_manager: PackageManager,
+  pub installed: bool,
+}
+
+impl WorkspaceConfig {
+  pub fn file_name(&self) -> &'static str {
+    match self {
+      WorkspaceConfig::Pnpm => PNPM_WORKSPACE,
+      WorkspaceConfig::PackageJson { .. } => "package.json",
+      WorkspaceConfig::Oxide => PROJECT_METADATA,
+    }
+  }
+}
+
+impl WorkspaceLayout {
+  pub fn find(start: &Path) -> Result<Self> {
+    let start = fs::canonicalize(start)?;
+
+    for dir in start.ancestors() {
+      if let Some(layout) = Self::detect(dir)? {
+        debug!(
+          "Found {} workspace at {}",
+          layout.config.file_name(),
+          dir.display()
+        );
+        return Ok(layout);
+      }
+    }
+
+    bail!(
+      "{} is not inside a workspace. Create one with `oxide new <name> --workspace`",
+      start.display()
+    )
+  }
+
+  fn detect(dir: &Path) -> Result<Option<Self>> {
+    let layout = |config, packages| {
+      Some(Self {
+        root: dir.to_path_buf(),
+        config,
+        packages,
+      })
+    };
+
+    let pnpm = dir.join(PNPM_WORKSPACE);
+    if pnpm.exists() {
+      let manifest: Value = serde_yaml::from_slice(&fs::read(&pnpm)?)
+        .map_err(|e| anyhow!("Could not parse {}: {}", pnpm.display(), e))?;
+      return Ok(layout(
+        WorkspaceConfig::Pnpm,
+        string_list(manifest.get("packages")),
+      ));
+    }
+
+    let package = dir.join("package.json");
+    if package.exists() {
+      let manifest: Value = serde_json::from_slice(&fs::read(&package)?)
+        .map_err(|e| anyhow!("Could not parse {}: {}", package.display(), e))?;
+      match manifest.get("workspaces") {
+        Some(Value::Array(_)) => {
+          return Ok(layout(
+            WorkspaceConfig::PackageJson { nested: false },
+            string_list(manifest.get("workspaces")),
+          ));
+        }
+        Some(Value::Object(workspaces)) => {
+          return Ok(layout(
+            WorkspaceConfig::PackageJson { nested: true },
+            string_list(workspaces.get("packages")),
+          ));
+        }
+        _ => {}
+      }
+    }
+
+    if let Some(workspace) = ProjectMetadata::load(dir)?.and_then(|m| m.workspace) {
+      return Ok(layout(WorkspaceConfig::Oxide, workspace.packages));
+    }
+
+    Ok(None)
+  }
+
+  pub fn includes(&self, member: &Path) -> bool {
+    let member = relative_key(member);
+    self
+      .packages
+      .iter()
+      .filter(|pattern| !pattern.starts_with('!'))
+      .any(|pattern| glob_matches(pattern, &member))
+      && self.excluded_by(&member).is_none()
+  }
+
+  fn excluded_by(&self, member: &str) -> Option<&str> {
+    self
+      .packages
+      .iter()
+      .filter_map(|pattern| pattern.strip_prefix('!'))
+      .find(|pattern| glob_matches(pattern, member))
+  }
+
+  pub fn package_manager(&self) -> PackageManager {
+    let recorded = ProjectMetadata::load(&self.root)
+      .ok()
+      .flatten()
+      .and_then(|m| m.package_manager);
+    if let Some(package_manager) = recorded {
+      return package_manager;
+    }
+
+    let lockfiles = [
+      ("pnpm-lock.yaml", PackageManager::PNPM),
+      ("yarn.lock", PackageManager::Yarn),
+      ("bun.lock", PackageManager::Bun),
+      ("bun.lockb", PackageManager::Bun),
+      ("package-lock.json", PackageManager::NPM),
+    ];
+    if let Some((_, package_manager)) = lockfiles
+      .iter()
+      .find(|(lockfile, _)| self.root.join(lockfile).exists())
+    {
+      return *package_manager;
+    }
+
+    match self.config {
+      WorkspaceConfig::Pnpm => PackageManager::PNPM,
+      _ => PackageManager::NPM,
+    }
+  }
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+  value
+    .and_then(Value::as_array)
+    .map(|items| {
+      items
+        .iter()
+        .filter_map(|item| {
+          item
+            .as_str()
+            .map(|s| s.trim_start_matches("./").to_string())
+        })
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+fn snapshot(root: &Path, files: &[&str]) -> Result<Vec<(PathBuf, Option<Vec<u8>>)>> {
+  let mut originals: Vec<(PathBuf, Option<Vec<u8>>)> = Vec::new();
+  for file in files {
+    let path = root.join(file);
+    if originals.iter().any(|(existing, _)| *existing == path) {
+      continue;
+    }
+    let contents = path.exists().then(|| fs::read(&path)).transpose()?;
+    originals.push((path, contents));
+  }
+  Ok(originals)
+}
+
+fn restore(originals: Vec<(PathBuf, Option<Vec<u8>>)>) -> Result<()> {
+  for (path, contents) in originals {
+    match contents {
+      Some(contents) => fs::write(&path, contents)?,
+      None if path.exists() => fs::remove_file(&path)?,
+      None => {}
+    }
+  }
+  Ok(())
+}
+
+fn glob_matches(pattern: &str, path: &str) -> bool {
+  let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+  let mut regex = String::from("^");
+  let mut rest = pattern;
+
+  while !rest.is_empty() {
+    if let Some(after) = rest.strip_prefix("**") {
+      regex.push_str(".*");
+      rest = after;
+    } else if let Some(after) = rest.strip_prefix('*') {
+      regex.push_str("[^/]*");
+      rest = after;
+    } else {
+      let next = rest.find('*').unwrap_or(rest.len());
+      regex.push_str(&regex::escape(&rest[..next]));
+      rest = &rest[next..];
+    }
+  }
+  regex.push('$');
+
+  Regex::new(&regex).is_ok_and(|r| r.is_match(path))
+}
+
+impl MemberAdder {
+  pub fn new(
+    workspace_dir: impl Into<PathBuf>,
+    path: impl Into<PathBuf>,
+    source: TemplateSource,
+  ) -> Self {
+    Self {
+      workspace_dir: workspace_dir.into(),
+      path: path.into(),
+      source,
+      package_manager: None,
+      install: false,
+      conflict_policy: None,
+      conflict_handler: None,
+      paths: None,
+      config: OxideConfig::default(),
+      cancel: CancelToken::new(),
+      events: EventSink::default(),
+    }
+  }
+
+  pub fn package_manager(mut self, package_manager: Option<PackageManager>) -> Self {
+    self.package_manager = package_manager;
+    self
+  }
+
+  pub fn install(mut self, install: bool) -> Self {
+    self.install = install;
+    self
+  }
+
+  pub fn conflict_policy(mut self, policy: Option<FileConflictPolicy>) -> Self {
+    self.conflict_policy = policy;
+    self
+  }
+
+  pub fn on_conflict(
+    mut self,
+    handler: impl Fn(&FileConflict) -> Result<ConflictResolution> + Send + Sync + 'static,
+  ) -> Self {
+    self.conflict_handler = Some(Arc::new(handler));
+    self
+  }
+
+  pub fn paths(mut self, paths: OxidePaths) -> Self {
+    self.paths = Some(paths);
+    self
+  }
+
+  pub fn config(mut self, config: OxideConfig) -> Self {
+    self.config = config;
+    self
+  }
+
+  pub fn cancel_token(mut self, cancel: CancelToken) -> Self {
+    self.cancel = cancel;
+    self
+  }
+
+  pub fn events(mut self, events: EventSink) -> Self {
+    self.events = events;
+    self
+  }
+
+  pub async fn add(self) -> Result<AddedMember> {
+    let paths = match &self.paths {
+      Some(paths) => paths.clone(),
+      None => OxidePaths::new()?,
+    };
+    paths.ensure_directories()?;
+
+    check_member_path(&self.path)?;
+    let layout = WorkspaceLayout::find(&self.workspace_dir)?;
+    let package_manager = self
+      .package_manager
+      .unwrap_or_else(|| layout.package_manager());
+
+    if self.install {
+      which::which(package_manager.to_string())
+        .map_err(|_| OxideError::PackageManagerMissing(package_manager.to_string()))?;
+    }
+
+    let (parent, name) = split_member_path(&self.path)?;
+    let mut generator = ProjectGenerator::new(self.source.clone(), name)
+      .output_dir(layout.root.join(parent))
+      .install(InstallPolicy::Skip)
+      .paths(paths)
+      .config(self.config.clone())
+      .cancel_token(self.cancel.clone())
+      .conflict_policy(self.conflict_policy)
+      .events(self.events.clone());
+    generator.conflict_handler = self.conflict_handler.clone();
+
+    let created = self
+      .path
+      .ancestors()
+      .filter(|dir| !dir.as_os_str().is_empty())
+      .map(|dir| layout.root.join(dir))
+      .take_while(|dir| !dir.exists())
+      .last();
+    let originals = snapshot(
+      &layout.root,
+      &[
+        layout.config.file_name(),
+        "package.json",
+        "tsconfig.json",
+        PROJECT_METADATA,
+      ],
+    )?;
+    let project = generator.generate().await?;
+
+    let wired = self.register(&layout).and_then(|registered| {
+      let references = self.add_reference(&layout)?;
+      add_dev_script(&layout.root, &self.path, &self.events)?;
+      self.record(&layout, &project.template, package_manager)?;
+      Ok((registered, references))
+    });
+    let (registered, references) = match wired {
+      Ok(wired) => wired,
+      Err(e) => {
+        restore(originals)?;
+        if let Some(created) = created
+          && created.exists()
+        {
+          fs::remove_dir_all(&created)?;
+          self
+            .events
+            .emit(GeneratorEvent::CleanedUp { path: created });
+        }
+        return Err(e);
+      }
+    };
+
+    if self.install {
+      let status =
+        install_dependencies(&package_manager, &layout.root, &self.cancel, &self.events).await?;
+      if !status.success() {
+        return Err(anyhow!(
+          "{} install failed with code {:?}",
+          package_manager,
+          status.code()
+        ));
+      }
+    }
+
+    Ok(AddedMember {
+      root: layout.root.display().to_string(),
+      path: relative_key(&self.path),
+      template: relative_key(&project.template),
+      files: project.files.len(),
+      registered,
+      references,
+      package_manager,
+      installed: self.install,
+    })
+  }
+
+  fn register(&self, layout: &WorkspaceLayout) -> Result<Option<String>> {
+    if layout.includes(&self.path) {
+      debug!(
+        "{} is already covered by the workspace globs",
+        self.path.display()
+      );
+      return Ok(None);
+    }
+
+    let key = match layout.config {
+      WorkspaceConfig::Pnpm => "packages",
+      WorkspaceConfig::PackageJson { nested: false } => "workspaces",
+      WorkspaceConfig::PackageJson { nested: true } => "workspaces.packages",
+      WorkspaceConfig::Oxide => return Ok(Some(PROJECT_METADATA.to_string())),
+    };
+
+    let member = relative_key(&self.path);
+    if let Some(pattern) = layout.excluded_by(&member) {
+      warn!(
+        "⚠ {} is excluded by '!{}' in {}, remove that pattern so it gets installed",
+        member,
+        pattern,
+        layout.config.file_name()
+      );
+    }
+
+    let mut append = Map::new();
+    append.insert(key.to_string(), json!([member]));
+    let file = layout.config.file_name();
+    self.patch(
+      &layout.root.join(file),
+      Patch {
+        append,
+        ..Default::default()
+      },
+    )?;
+    Ok(Some(file.to_string()))
+  }
+
+  fn add_reference(&self, layout: &WorkspaceLayout) -> Result<Option<String>> {
+    let root_tsconfig = layout.root.join("tsconfig.json");
+    let member_tsconfig = layout.root.join(&self.path).join("tsconfig.json");
+    if !root_tsconfig.exists() || !member_tsconfig.exists() {
+      return Ok(None);
+    }
+
+    let uses_references = serde_json::from_slice::<Value>(&fs::read(&root_tsconfig)?)
+      .map(|tsconfig| tsconfig.get("references").is_some())
+      .unwrap_or(false);
+    if !uses_references {
+      debug!(
+        "{} does not use project references (or is not plain JSON)",
+        root_tsconfig.display()
+      );
+      return Ok(None);
+    }
+
+    let mut append = Map::new();
+    append.insert(
+      "references".to_string(),
+      json!([{ "path": format!("./{}", relative_key(&self.path)) }]),
+    );
+    self.patch(
+      &root_tsconfig,
+      Patch {
+        append,
+        ..Default::default()
+      },
+    )?;
+
+    let composite = Patch {
+      merge: Some(json!({ "compilerOptions": { "composite": true } })),
+      ..Default::default()
+    };
+    if let Err(e) = self.patch(&member_tsconfig, composite) {
+      debug!(
+        "Could not enable composite in {}: {}",
+        member_tsconfig.display(),
+        e
+      );
+    }
+
+    Ok(Some("tsconfig.json".to_string()))
+  }
+
+  fn record(
+    &self,
+    layout: &WorkspaceLayout,
+    template: &Path,
+    package_manager: PackageManager,
+  ) -> Result<()> {
+    let mut metadata = match ProjectMetadata::load(&layout.root)? {
+      Some(metadata) => metadata,
+      None => ProjectMetadata {
+        name: dir_name(&layout.root)?,
+        ..Default::default()
+      },
+    };
+    metadata.package_manager.get_or_insert(package_manager);
+
+    let workspace = metadata.workspace.get_or_insert_with(|| WorkspaceRecord {
+      packages: layout.packages.clone(),
+      ..Default::default()
+    });
+    let key = relative_key(&self.path);
+    if !layout.includes(&self.path) && !workspace.packages.contains(&key) {
+      workspace.packages.push(key.clone());
+    }
+
+    let record = MemberRecord {
+      path: key.clone(),
+      template: Some(relative_key(template)),
+    };
+    match workspace.members.iter_mut().find(|m| m.path == key) {
+      Some(existing) => *existing = record,
+      None => workspace.members.push(record),
+    }
+
+    metadata.save(&layout.root)
+  }
+
+  fn patch(&self, path: &Path, patch: Patch) -> Result<()> {
+    let existing = fs::read(path)?;
+    if let Some(patched) = patch_document(path, Some(&existing), &[&patch])? {
+      fs::write(path, patched)?;
+      self.events.emit(GeneratorEvent::FilePatched {
+        path: path.to_path_buf(),
+      });
+    }
+    Ok(())
+  }
+}
+
+impl Render for AddedMember {
+  fn to_table(&self) -> String {
+    let mut table = Table::new();
+    table.set_header(vec![
+      Cell::new("Step").add_attribute(Attribute::Bold),
+      Cell::new("Result").add_attribute(Attribute::Bold),
+    ]);
+
+    table.add_row(vec![
+      Cell::new("Rendered"),
+      Cell::new(format!("{} files from {}", self.files, self.template)),
+    ]);
+    table.add_row(vec![
+      Cell::new("Registered"),
+      Cell::new(match &self.registered {
+        Some(file) => format!("added to {}", file),
+        None => "already covered by workspace globs".to_string(),
+      }),
+    ]);
+    if let Some(references) = &self.references {
+      table.add_row(vec![
+        Cell::new("References"),
+        Cell::new(format!("added to {}", references)),
+      ]);
+    }
+    table.add_row(vec![
+      Cell::new("Install"),
+      Cell::new(if self.installed {
+        format!("{} install (root)", self.package_manager)
+      } else {
+        "skipped".to_string()
+      }),
+    ]);
+
+    format!("✓ Added {}\n{}", self.path, table)
+  }
+
+  fn to_plain(&self) -> String {
+    format!("{}\t{}", self.path, self.template)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn layout(packages: &[&str]) -> WorkspaceLayout {
+    WorkspaceLayout {
+      root: PathBuf::from("/workspace"),
+      config: WorkspaceConfig::Pnpm,
+      packages: packages.iter().map(|p| p.to_string()).collect(),
+    }
+  }
+
+  #[test]
+  fn includes_members_matching_globs() {
+    let layout = layout(&["apps/*", "packages/**"]);
+
+    assert!(layout.includes(Path::new("apps/web")));
+    assert!(layout.includes(Path::new("packages/ui/core")));
+    assert!(!layout.includes(Path::new("tools/cli")));
+    assert!(!layout.includes(Path::new("apps/web/nested")));
+  }
+
+  #[test]
+  fn negated_globs_exclude_members() {
+    let layout = layout(&["packages/*", "packages/legacy/*", "!packages/legacy/*"]);
+
+    assert!(layout.includes(Path::new("packages/ui")));
+    assert!(!layout.includes(Path::new("packages/legacy/old")));
+    assert_eq!(
+      layout.excluded_by("packages/legacy/old"),
+      Some("packages/legacy/*")
+    );
+  }
+}
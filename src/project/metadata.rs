@@ -0,0 +1,88 @@
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::OxideError, project::workspace::WorkspaceTool, prompts::PackageManager};
+
+pub const PROJECT_METADATA: &str = "oxide.json";
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ProjectMetadata {
+  pub name: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub template: Option<String>,
+  #[serde(
+    rename = "packageManager",
+    default,
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub package_manager: Option<PackageManager>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub features: Vec<FeatureRecord>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub workspace: Option<WorkspaceRecord>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FeatureRecord {
+  pub name: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  #[serde(rename = "appliedAt")]
+  pub applied_at: String,
+  #[serde(default)]
+  pub files: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct WorkspaceRecord {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tool: Option<WorkspaceTool>,
+  pub packages: Vec<String>,
+  #[serde(default)]
+  pub members: Vec<MemberRecord>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MemberRecord {
+  pub path: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub template: Option<String>,
+}
+
+impl ProjectMetadata {
+  pub fn load(project_dir: &Path) -> Result<Option<Self>> {
+    let path = project_dir.join(PROJECT_METADATA);
+    if !path.exists() {
+      return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let metadata =
+      serde_json::from_str(&content).map_err(|source| OxideError::InvalidManifest {
+        path: path.display().to_string(),
+        source,
+      })?;
+    Ok(Some(metadata))
+  }
+
+  pub fn save(&self, project_dir: &Path) -> Result<()> {
+    fs::write(
+      project_dir.join(PROJECT_METADATA),
+      format!("{}\n", serde_json::to_string_pretty(self)?),
+    )?;
+    Ok(())
+  }
+
+  pub fn feature(&self, name: &str) -> Option<&FeatureRecord> {
+    self.features.iter().find(|f| f.name == name)
+  }
+
+  pub fn record_feature(&mut self, record: FeatureRecord) {
+    match self.features.iter_mut().find(|f| f.name == record.name) {
+      Some(existing) => *existing = record,
+      None => self.features.push(record),
+    }
+  }
+}
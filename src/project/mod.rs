@@ -0,0 +1,411 @@
+pub mod conflict;
+pub mod events;
+pub mod feature;
+pub mod fullstack;
+pub mod member;
+pub mod metadata;
+pub mod patch;
+pub mod preview;
+pub mod workspace;
+
+use std::{
+  collections::BTreeMap,
+  fs,
+  path::{Path, PathBuf},
+  process::{ExitStatus, Stdio},
+  sync::Arc,
+  time::Duration,
+};
+
+use anyhow::{Result, anyhow};
+use log::debug;
+use tokio::{
+  io::{AsyncBufReadExt, AsyncRead, BufReader},
+  process::Command,
+  sync::mpsc,
+};
+
+use crate::{
+  config::OxideConfig,
+  error::OxideError,
+  paths::OxidePaths,
+  project::{
+    conflict::{
+      ConflictHandler, ConflictResolution, FileConflict, FileConflictPolicy, WriteSummary,
+      write_files,
+    },
+    events::{EventSink, GeneratorEvent},
+    metadata::ProjectMetadata,
+    patch::{apply_patches, preview_patches, split_patches},
+  },
+  prompts::PackageManager,
+  templates::{
+    OxideTemplate,
+    catalogue::TemplateSelection,
+    compose::TemplateTree,
+    generator::{RenderedFile, extract_template},
+    loader::get_files,
+    registry::{get_catalogue, get_registry},
+  },
+  utils::{cancel::CancelToken, fs::relative_key},
+};
+
+#[derive(Clone)]
+pub enum TemplateSource {
+  Name(String),
+  Path(PathBuf),
+  Selection(TemplateSelection),
+}
+
+pub enum InstallPolicy {
+  Skip,
+  Run(PackageManager),
+}
+
+pub struct GeneratedProject {
+  pub path: PathBuf,
+  pub template: PathBuf,
+  pub files: Vec<RenderedFile>,
+  pub patched: Vec<PathBuf>,
+  pub summary: WriteSummary,
+}
+
+pub struct ProjectGenerator {
+  source: TemplateSource,
+  project_name: String,
+  output_dir: PathBuf,
+  answers: BTreeMap<String, String>,
+  install: InstallPolicy,
+  dry_run: bool,
+  conflict_policy: Option<FileConflictPolicy>,
+  conflict_handler: Option<ConflictHandler>,
+  paths: Option<OxidePaths>,
+  config: OxideConfig,
+  cancel: CancelToken,
+  events: EventSink,
+}
+
+impl ProjectGenerator {
+  pub fn new(source: TemplateSource, project_name: impl Into<String>) -> Self {
+    Self {
+      source,
+      project_name: project_name.into(),
+      output_dir: PathBuf::new(),
+      answers: BTreeMap::new(),
+      install: InstallPolicy::Skip,
+      dry_run: false,
+      conflict_policy: None,
+      conflict_handler: None,
+      paths: None,
+      config: OxideConfig::default(),
+      cancel: CancelToken::new(),
+      events: EventSink::default(),
+    }
+  }
+
+  pub fn output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+    self.output_dir = output_dir.into();
+    self
+  }
+
+  pub fn answer(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.answers.insert(name.into(), value.into());
+    self
+  }
+
+  pub fn install(mut self, install: InstallPolicy) -> Self {
+    self.install = install;
+    self
+  }
+
+  pub fn dry_run(mut self, dry_run: bool) -> Self {
+    self.dry_run = dry_run;
+    self
+  }
+
+  pub fn conflict_policy(mut self, policy: Option<FileConflictPolicy>) -> Self {
+    self.conflict_policy = policy;
+    self
+  }
+
+  pub fn on_conflict(
+    mut self,
+    handler: impl Fn(&FileConflict) -> Result<ConflictResolution> + Send + Sync + 'static,
+  ) -> Self {
+    self.conflict_handler = Some(Arc::new(handler));
+    self
+  }
+
+  pub fn paths(mut self, paths: OxidePaths) -> Self {
+    self.paths = Some(paths);
+    self
+  }
+
+  pub fn config(mut self, config: OxideConfig) -> Self {
+    self.config = config;
+    self
+  }
+
+  pub fn cancel_token(mut self, cancel: CancelToken) -> Self {
+    self.cancel = cancel;
+    self
+  }
+
+  pub fn on_event(mut self, handler: impl Fn(&GeneratorEvent) + Send + Sync + 'static) -> Self {
+    self.events = EventSink::new(handler);
+    self
+  }
+
+  pub fn events(mut self, events: EventSink) -> Self {
+    self.events = events;
+    self
+  }
+
+  pub async fn generate(self) -> Result<GeneratedProject> {
+    let paths = match &self.paths {
+      Some(paths) => paths.clone(),
+      None => OxidePaths::new()?,
+    };
+    paths.ensure_directories()?;
+
+    let output_path = self.output_dir.join(&self.project_name);
+    let project_name = self.resolve_project_name(&output_path)?;
+
+    if !self.dry_run
+      && let InstallPolicy::Run(package_manager) = &self.install
+    {
+      which::which(package_manager.to_string())
+        .map_err(|_| OxideError::PackageManagerMissing(package_manager.to_string()))?;
+    }
+
+    let template = self.resolve_template(&paths).await?;
+    debug!("Resolved template path {}", template.display());
+    self.events.emit(GeneratorEvent::TemplateResolved {
+      path: template.clone(),
+    });
+
+    let loaded = get_files(&template, &paths, &self.config, &self.cancel, &self.events).await?;
+
+    let mut variables = template_defaults(&paths.templates, &loaded.tree);
+    variables.extend(self.answers.clone());
+
+    let files = extract_template(
+      &loaded.files,
+      &output_path,
+      &project_name,
+      &variables,
+      &self.cancel,
+      &self.events,
+    )?;
+    let (files, pending) = split_patches(files)?;
+
+    if self.dry_run {
+      let patched = preview_patches(&output_path, &pending)?;
+      return Ok(GeneratedProject {
+        path: output_path,
+        template,
+        patched: patched.iter().map(|file| file.path.clone()).collect(),
+        files: files.into_iter().chain(patched).collect(),
+        summary: WriteSummary::default(),
+      });
+    }
+
+    let created = !output_path.exists();
+    let written = write_files(
+      &output_path,
+      &files,
+      self.conflict_policy,
+      self.conflict_handler.as_ref(),
+      &self.events,
+    )
+    .and_then(|summary| {
+      let patched = apply_patches(&output_path, &pending, &self.events)?;
+      self.record_metadata(&output_path, &project_name, &template)?;
+      Ok((summary, patched.patched))
+    });
+
+    let installed = match (&written, &self.install) {
+      (Ok(_), InstallPolicy::Run(package_manager)) => {
+        install_dependencies(package_manager, &output_path, &self.cancel, &self.events)
+          .await
+          .map(Some)
+      }
+      _ => Ok(None),
+    };
+
+    if created && output_path.exists() && (written.is_err() || self.cancel.is_cancelled()) {
+      fs::remove_dir_all(&output_path)?;
+      self.events.emit(GeneratorEvent::CleanedUp {
+        path: output_path.clone(),
+      });
+    }
+
+    let (summary, patched) = written?;
+    if let Some(status) = installed?
+      && !status.success()
+      && let InstallPolicy::Run(package_manager) = &self.install
+    {
+      return Err(anyhow!(
+        "{} install failed with code {:?}",
+        package_manager,
+        status.code()
+      ));
+    }
+
+    Ok(GeneratedProject {
+      path: output_path,
+      template,
+      files,
+      patched,
+      summary,
+    })
+  }
+
+  fn record_metadata(&self, output_path: &Path, project_name: &str, template: &Path) -> Result<()> {
+    let mut metadata = ProjectMetadata::load(output_path)?.unwrap_or_default();
+    metadata.name = project_name.to_string();
+    metadata.template = Some(relative_key(template));
+    if let InstallPolicy::Run(package_manager) = &self.install {
+      metadata.package_manager = Some(*package_manager);
+    }
+    metadata.save(output_path)
+  }
+
+  fn resolve_project_name(&self, output_path: &Path) -> Result<String> {
+    if self.project_name != "." {
+      return Ok(self.project_name.clone());
+    }
+
+    dir_name(output_path)
+  }
+
+  async fn resolve_template(&self, paths: &OxidePaths) -> Result<PathBuf> {
+    match &self.source {
+      TemplateSource::Path(path) => Ok(path.clone()),
+      TemplateSource::Name(name) => {
+        let registry = get_registry(&paths.registry, &self.config).await?;
+        registry
+          .iter()
+          .find(|t| &t.name == name)
+          .map(|t| PathBuf::from(&t.path))
+          .ok_or_else(|| OxideError::TemplateNotFound(name.clone()).into())
+      }
+      TemplateSource::Selection(selection) => get_catalogue(&paths.registry, &self.config)
+        .await
+        .resolve(selection),
+    }
+  }
+}
+
+pub(crate) fn dir_name(path: &Path) -> Result<String> {
+  let absolute = if path.exists() {
+    fs::canonicalize(path)?
+  } else {
+    std::path::absolute(path)?
+  };
+
+  absolute
+    .file_name()
+    .map(|name| name.to_string_lossy().to_string())
+    .ok_or_else(|| {
+      anyhow!(
+        "Could not derive a project name from {}",
+        absolute.display()
+      )
+    })
+}
+
+pub(crate) fn template_defaults(
+  template_path: &Path,
+  tree: &TemplateTree,
+) -> BTreeMap<String, String> {
+  let mut defaults = BTreeMap::new();
+
+  for layer in tree.layers() {
+    let manifest = fs::read_to_string(template_path.join(&layer.path).join("oxide.template.json"))
+      .ok()
+      .and_then(|content| serde_json::from_str::<OxideTemplate>(&content).ok());
+
+    for variable in manifest.map(|m| m.variables).unwrap_or_default() {
+      if let Some(default) = variable.default {
+        defaults.insert(variable.name, default);
+      }
+    }
+  }
+
+  defaults
+}
+
+pub async fn install_dependencies(
+  package_manager: &PackageManager,
+  project_path: &Path,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<ExitStatus> {
+  debug!(
+    "Running {} install in {}",
+    package_manager,
+    project_path.display()
+  );
+
+  let mut child = Command::new(package_manager.to_string())
+    .arg("install")
+    .current_dir(project_path)
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .kill_on_drop(true)
+    .spawn()
+    .map_err(|e| anyhow!(e))?;
+
+  events.emit(GeneratorEvent::InstallStarted {
+    package_manager: *package_manager,
+  });
+
+  let (tx, mut rx) = mpsc::unbounded_channel();
+  if let Some(stdout) = child.stdout.take() {
+    forward_lines(stdout, tx.clone());
+  }
+  if let Some(stderr) = child.stderr.take() {
+    forward_lines(stderr, tx.clone());
+  }
+  drop(tx);
+
+  let status = loop {
+    tokio::select! {
+      Some(line) = rx.recv() => events.emit(GeneratorEvent::InstallOutput { line }),
+      status = child.wait() => break status?,
+      _ = cancel.cancelled() => {
+        child.kill().await?;
+        events.emit(GeneratorEvent::InstallFinished { success: false });
+        return Err(OxideError::Cancelled.into());
+      }
+    }
+  };
+
+  let _ = tokio::time::timeout(Duration::from_secs(1), async {
+    while let Some(line) = rx.recv().await {
+      events.emit(GeneratorEvent::InstallOutput { line });
+    }
+  })
+  .await;
+
+  events.emit(GeneratorEvent::InstallFinished {
+    success: status.success(),
+  });
+
+  Ok(status)
+}
+
+fn forward_lines(
+  reader: impl AsyncRead + Unpin + Send + 'static,
+  tx: mpsc::UnboundedSender<String>,
+) {
+  tokio::spawn(async move {
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+      if tx.send(line).is_err() {
+        break;
+      }
+    }
+  });
+}
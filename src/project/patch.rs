@@ -0,0 +1,566 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value, ser::PrettyFormatter};
+
+use crate::{
+  project::events::{EventSink, GeneratorEvent},
+  templates::generator::RenderedFile,
+};
+
+const MERGE_SUFFIX: &str = ".merge.json";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DocumentFormat {
+  Json,
+  Yaml,
+  Toml,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Patch {
+  #[serde(default)]
+  pub merge: Option<Value>,
+  #[serde(default)]
+  pub append: Map<String, Value>,
+  #[serde(default)]
+  pub delete: Vec<KeyPath>,
+  #[serde(default)]
+  pub dependencies: Map<String, Value>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+pub enum KeyPath {
+  Dotted(String),
+  Segments(Vec<String>),
+}
+
+pub struct PendingPatch {
+  pub source: PathBuf,
+  pub target: PathBuf,
+  pub patch: Patch,
+}
+
+#[derive(Default)]
+pub struct PatchSummary {
+  pub patched: Vec<PathBuf>,
+  pub unchanged: Vec<PathBuf>,
+}
+
+impl DocumentFormat {
+  pub fn from_path(path: &Path) -> Option<Self> {
+    match path.extension()?.to_str()? {
+      "json" => Some(DocumentFormat::Json),
+      "yaml" | "yml" => Some(DocumentFormat::Yaml),
+      "toml" => Some(DocumentFormat::Toml),
+      _ => None,
+    }
+  }
+
+  fn parse<T: DeserializeOwned>(&self, bytes: &[u8], path: &Path) -> Result<T> {
+    let parsed = match self {
+      DocumentFormat::Json => serde_json::from_slice(bytes).map_err(|e| e.to_string()),
+      DocumentFormat::Yaml => serde_yaml::from_slice(bytes).map_err(|e| e.to_string()),
+      DocumentFormat::Toml => std::str::from_utf8(bytes)
+        .map_err(|e| e.to_string())
+        .and_then(|s| toml::from_str(s).map_err(|e| e.to_string())),
+    };
+
+    parsed.map_err(|e| anyhow!("Could not parse {}: {}", path.display(), e))
+  }
+
+  fn write(&self, value: &Value, original: Option<&[u8]>) -> Result<Vec<u8>> {
+    let mut output = match self {
+      DocumentFormat::Json => {
+        let indent = original.map(json_indent).unwrap_or_else(|| b"  ".to_vec());
+        let mut output = Vec::new();
+        let mut serializer = serde_json::Serializer::with_formatter(
+          &mut output,
+          PrettyFormatter::with_indent(&indent),
+        );
+        value.serialize(&mut serializer)?;
+        output
+      }
+      DocumentFormat::Yaml => serde_yaml::to_string(value)?.into_bytes(),
+      DocumentFormat::Toml => toml::to_string_pretty(value)?.into_bytes(),
+    };
+
+    let trailing_newline = original.is_none_or(|o| o.ends_with(b"\n"));
+    while output.ends_with(b"\n") {
+      output.pop();
+    }
+    if trailing_newline {
+      output.push(b'\n');
+    }
+    Ok(output)
+  }
+}
+
+fn json_indent(original: &[u8]) -> Vec<u8> {
+  String::from_utf8_lossy(original)
+    .lines()
+    .skip(1)
+    .find(|line| !line.trim().is_empty())
+    .map(|line| {
+      line
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect::<String>()
+        .into_bytes()
+    })
+    .filter(|indent| !indent.is_empty())
+    .unwrap_or_else(|| b"  ".to_vec())
+}
+
+pub fn patch_target(path: &Path) -> Option<(PathBuf, DocumentFormat)> {
+  let name = path.file_name()?.to_str()?;
+
+  if let Some(stem) = name.strip_suffix(MERGE_SUFFIX) {
+    return Some((
+      path.with_file_name(format!("{}.json", stem)),
+      DocumentFormat::Json,
+    ));
+  }
+
+  let (target, extension) = name.rsplit_once('.')?;
+  let target = target.strip_suffix(".patch")?;
+  let format = DocumentFormat::from_path(Path::new(&format!("patch.{}", extension)))?;
+  Some((path.with_file_name(target), format))
+}
+
+impl Patch {
+  pub fn parse(file: &RenderedFile) -> Result<(PathBuf, Self)> {
+    let (target, format) =
+      patch_target(&file.path).ok_or_else(|| anyhow!("{} is not a patch", file.path.display()))?;
+
+    let name = file.path.file_name().unwrap_or_default().to_string_lossy();
+    let patch = if name.ends_with(MERGE_SUFFIX) {
+      Patch {
+        merge: Some(format.parse(&file.contents, &file.path)?),
+        ..Default::default()
+      }
+    } else {
+      format.parse(&file.contents, &file.path)?
+    };
+
+    Ok((target, patch))
+  }
+
+  pub fn apply(&self, document: &mut Value) -> Result<()> {
+    if let Some(merge) = &self.merge {
+      merge_value(document, merge.clone());
+    }
+
+    for (path, values) in &self.append {
+      let segments = KeyPath::Dotted(path.clone()).segments();
+      let target = entry(document, &segments)?;
+      if target.is_null() {
+        *target = Value::Array(Vec::new());
+      }
+
+      let Value::Array(items) = target else {
+        bail!("Cannot append to '{}': it is not an array", path);
+      };
+      let values = match values {
+        Value::Array(values) => values.clone(),
+        value => vec![value.clone()],
+      };
+      for value in values {
+        if !items.contains(&value) {
+          items.push(value);
+        }
+      }
+    }
+
+    for path in &self.delete {
+      remove(document, &path.segments());
+    }
+
+    for (section, packages) in &self.dependencies {
+      let Value::Object(packages) = packages else {
+        bail!("Dependencies for '{}' must be an object", section);
+      };
+
+      let target = entry(document, std::slice::from_ref(section))?;
+      if target.is_null() {
+        *target = Value::Object(Map::new());
+      }
+      let Value::Object(installed) = target else {
+        bail!("'{}' is not an object", section);
+      };
+
+      for (name, version) in packages {
+        let upgrade = match (installed.get(name), version) {
+          (None, _) => true,
+          (Some(Value::String(current)), Value::String(wanted)) => wants_upgrade(wanted, current),
+          _ => false,
+        };
+        if upgrade {
+          installed.insert(name.clone(), version.clone());
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl KeyPath {
+  pub fn segments(&self) -> Vec<String> {
+    match self {
+      KeyPath::Dotted(path) => path.split('.').map(|s| s.to_string()).collect(),
+      KeyPath::Segments(segments) => segments.clone(),
+    }
+  }
+}
+
+fn entry<'a>(document: &'a mut Value, segments: &[String]) -> Result<&'a mut Value> {
+  let mut current = document;
+  for segment in segments {
+    if current.is_null() {
+      *current = Value::Object(Map::new());
+    }
+    let Value::Object(map) = current else {
+      bail!("Cannot descend into '{}': parent is not an object", segment);
+    };
+    current = map.entry(segment.clone()).or_insert(Value::Null);
+  }
+  Ok(current)
+}
+
+fn remove(document: &mut Value, segments: &[String]) {
+  let Some((last, parents)) = segments.split_last() else {
+    return;
+  };
+
+  let mut current = document;
+  for segment in parents {
+    match current.get_mut(segment) {
+      Some(next) => current = next,
+      None => return,
+    }
+  }
+  if let Value::Object(map) = current {
+    map.shift_remove(last);
+  }
+}
+
+pub fn merge_value(base: &mut Value, patch: Value) {
+  match (base, patch) {
+    (Value::Object(base), Value::Object(patch)) => {
+      for (key, value) in patch {
+        match base.get_mut(&key) {
+          Some(existing) => merge_value(existing, value),
+          None => {
+            base.insert(key, value);
+          }
+        }
+      }
+    }
+    (Value::Array(base), Value::Array(patch)) => {
+      for item in patch {
+        if !base.contains(&item) {
+          base.push(item);
+        }
+      }
+    }
+    (base, patch) => *base = patch,
+  }
+}
+
+fn minimum_version(requirement: &str) -> Option<semver::Version> {
+  let version = requirement
+    .trim()
+    .trim_start_matches(['^', '~', '=', '>', '<', 'v', ' '])
+    .split_whitespace()
+    .next()?;
+
+  let mut parts: Vec<&str> = version.split('.').collect();
+  while parts.len() < 3 {
+    parts.push("0");
+  }
+  semver::Version::parse(&parts.join(".")).ok()
+}
+
+fn wants_upgrade(wanted: &str, current: &str) -> bool {
+  match (minimum_version(wanted), minimum_version(current)) {
+    (Some(wanted), Some(current)) => wanted > current,
+    _ => false,
+  }
+}
+
+pub fn patch_document(
+  target: &Path,
+  existing: Option<&[u8]>,
+  patches: &[&Patch],
+) -> Result<Option<Vec<u8>>> {
+  let format = DocumentFormat::from_path(target).ok_or_else(|| {
+    anyhow!(
+      "Cannot patch {}: only JSON, YAML and TOML files are supported",
+      target.display()
+    )
+  })?;
+
+  let original: Option<Value> = existing
+    .map(|bytes| format.parse(bytes, target))
+    .transpose()?;
+
+  let mut document = original
+    .clone()
+    .unwrap_or_else(|| Value::Object(Map::new()));
+  for patch in patches {
+    patch
+      .apply(&mut document)
+      .map_err(|e| anyhow!("Could not patch {}: {}", target.display(), e))?;
+  }
+
+  if original.as_ref() == Some(&document) {
+    return Ok(None);
+  }
+
+  Ok(Some(format.write(&document, existing)?))
+}
+
+pub fn split_patches(files: Vec<RenderedFile>) -> Result<(Vec<RenderedFile>, Vec<PendingPatch>)> {
+  let (mut patch_files, mut files): (Vec<_>, Vec<_>) = files
+    .into_iter()
+    .partition(|file| patch_target(&file.path).is_some());
+  patch_files.sort_by(|a, b| a.path.cmp(&b.path));
+
+  let mut pending = Vec::new();
+  for file in patch_files {
+    let (target, patch) = Patch::parse(&file)?;
+
+    match files.iter_mut().find(|f| f.path == target) {
+      Some(generated) => {
+        if let Some(patched) = patch_document(&target, Some(&generated.contents), &[&patch])? {
+          generated.contents = patched;
+          generated.rendered = true;
+        }
+      }
+      None => pending.push(PendingPatch {
+        source: file.path,
+        target,
+        patch,
+      }),
+    }
+  }
+
+  Ok((files, pending))
+}
+
+pub fn apply_patches(
+  output_path: &Path,
+  pending: &[PendingPatch],
+  events: &EventSink,
+) -> Result<PatchSummary> {
+  let mut summary = PatchSummary::default();
+  for (target, patched) in patch_targets(output_path, pending)? {
+    match patched {
+      Some(patched) => {
+        let target_path = output_path.join(&target);
+        if let Some(parent) = target_path.parent() {
+          fs::create_dir_all(parent)?;
+        }
+        fs::write(&target_path, patched)?;
+        events.emit(GeneratorEvent::FilePatched { path: target_path });
+        summary.patched.push(target);
+      }
+      None => summary.unchanged.push(target),
+    }
+  }
+
+  Ok(summary)
+}
+
+pub fn preview_patches(output_path: &Path, pending: &[PendingPatch]) -> Result<Vec<RenderedFile>> {
+  Ok(
+    patch_targets(output_path, pending)?
+      .into_iter()
+      .filter_map(|(path, patched)| {
+        patched.map(|contents| RenderedFile {
+          path,
+          contents,
+          rendered: true,
+        })
+      })
+      .collect(),
+  )
+}
+
+fn patch_targets(
+  output_path: &Path,
+  pending: &[PendingPatch],
+) -> Result<Vec<(PathBuf, Option<Vec<u8>>)>> {
+  let mut targets: Vec<&Path> = pending.iter().map(|p| p.target.as_path()).collect();
+  targets.sort();
+  targets.dedup();
+
+  targets
+    .into_iter()
+    .map(|target| {
+      let patches: Vec<&Patch> = pending
+        .iter()
+        .filter(|p| p.target == target)
+        .map(|p| &p.patch)
+        .collect();
+
+      let target_path = output_path.join(target);
+      let existing = target_path
+        .exists()
+        .then(|| fs::read(&target_path))
+        .transpose()?;
+
+      let patched = patch_document(target, existing.as_deref(), &patches)?;
+      Ok((target.to_path_buf(), patched))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn patch(value: Value) -> Patch {
+    serde_json::from_value(value).unwrap()
+  }
+
+  #[test]
+  fn merges_nested_objects() {
+    let mut document = json!({ "compilerOptions": { "strict": false, "target": "ES2020" } });
+    patch(json!({ "merge": { "compilerOptions": { "strict": true, "jsx": "react-jsx" } } }))
+      .apply(&mut document)
+      .unwrap();
+
+    assert_eq!(
+      document,
+      json!({ "compilerOptions": { "strict": true, "target": "ES2020", "jsx": "react-jsx" } })
+    );
+  }
+
+  #[test]
+  fn appends_without_duplicates() {
+    let mut document = json!({ "plugins": { "list": ["a", "b"] } });
+    patch(json!({ "append": { "plugins.list": ["b", "c"] } }))
+      .apply(&mut document)
+      .unwrap();
+
+    assert_eq!(document, json!({ "plugins": { "list": ["a", "b", "c"] } }));
+  }
+
+  #[test]
+  fn deletes_dotted_and_segmented_keys() {
+    let mut document = json!({ "scripts": { "lint": "eslint", "test": "jest" }, "a.b": 1 });
+    patch(json!({ "delete": ["scripts.lint", ["a.b"], "missing.key"] }))
+      .apply(&mut document)
+      .unwrap();
+
+    assert_eq!(document, json!({ "scripts": { "test": "jest" } }));
+  }
+
+  #[test]
+  fn upgrades_only_older_dependencies() {
+    let mut document = json!({ "dependencies": { "react": "^18.2.0", "vite": "^6.0.0" } });
+    patch(json!({
+      "dependencies": {
+        "dependencies": { "react": "^19.0.0", "vite": "^5.4.0", "zod": "^3.23.0" }
+      }
+    }))
+    .apply(&mut document)
+    .unwrap();
+
+    assert_eq!(
+      document,
+      json!({ "dependencies": { "react": "^19.0.0", "vite": "^6.0.0", "zod": "^3.23.0" } })
+    );
+  }
+
+  #[test]
+  fn dependencies_keep_existing_order() {
+    let existing =
+      b"{\n  \"dependencies\": {\n    \"zod\": \"^3.0.0\",\n    \"react\": \"^18.2.0\"\n  }\n}\n";
+    let patched = patch_document(
+      Path::new("package.json"),
+      Some(existing),
+      &[&patch(json!({
+        "dependencies": { "dependencies": { "axios": "^1.7.0", "react": "^19.0.0" } }
+      }))],
+    )
+    .unwrap()
+    .unwrap();
+
+    let document: Value = serde_json::from_slice(&patched).unwrap();
+    let names: Vec<&str> = document["dependencies"]
+      .as_object()
+      .unwrap()
+      .keys()
+      .map(String::as_str)
+      .collect();
+    assert_eq!(names, ["zod", "react", "axios"]);
+    assert_eq!(document["dependencies"]["react"], "^19.0.0");
+  }
+
+  #[test]
+  fn wants_upgrade_compares_minimum_versions() {
+    assert!(wants_upgrade("^2", "^1.9.9"));
+    assert!(!wants_upgrade("~1.2.0", "1.2.0"));
+    assert!(!wants_upgrade("latest", "^1.0.0"));
+  }
+
+  #[test]
+  fn merges_toml_tables() {
+    let existing = b"[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1\"\n";
+    let patched = patch_document(
+      Path::new("Cargo.toml"),
+      Some(existing),
+      &[&patch(json!({
+        "merge": { "dependencies": { "tokio": "1" }, "package": { "edition": "2024" } }
+      }))],
+    )
+    .unwrap()
+    .unwrap();
+
+    let document: Value = toml::from_str(std::str::from_utf8(&patched).unwrap()).unwrap();
+    assert_eq!(
+      document,
+      json!({
+        "package": { "name": "app", "edition": "2024" },
+        "dependencies": { "serde": "1", "tokio": "1" }
+      })
+    );
+  }
+
+  #[test]
+  fn unchanged_document_is_not_rewritten() {
+    let existing = b"{\n  \"name\": \"app\"\n}\n";
+    let patched = patch_document(
+      Path::new("package.json"),
+      Some(existing),
+      &[&patch(json!({ "merge": { "name": "app" } }))],
+    )
+    .unwrap();
+
+    assert!(patched.is_none());
+  }
+
+  #[test]
+  fn resolves_patch_targets() {
+    assert_eq!(
+      patch_target(Path::new("src/tsconfig.merge.json")),
+      Some((PathBuf::from("src/tsconfig.json"), DocumentFormat::Json))
+    );
+    assert_eq!(
+      patch_target(Path::new("Cargo.toml.patch.yaml")),
+      Some((PathBuf::from("Cargo.toml"), DocumentFormat::Yaml))
+    );
+    assert_eq!(patch_target(Path::new("package.json")), None);
+  }
+}
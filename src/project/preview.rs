@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+
+use crate::{
+  output::Render,
+  project::GeneratedProject,
+  utils::fs::{format_size, relative_key},
+};
+
+#[derive(Serialize)]
+pub struct DryRunReport {
+  pub root: String,
+  pub template: String,
+  pub files: Vec<DryRunFile>,
+}
+
+#[derive(Serialize)]
+pub struct DryRunFile {
+  pub path: String,
+  pub size: u64,
+  pub rendered: bool,
+  pub patched: bool,
+}
+
+impl DryRunReport {
+  pub fn new(project: &GeneratedProject) -> Self {
+    let mut files: Vec<DryRunFile> = project
+      .files
+      .iter()
+      .map(|file| DryRunFile {
+        path: relative_key(&file.path),
+        size: file.contents.len() as u64,
+        rendered: file.rendered,
+        patched: project.patched.contains(&file.path),
+      })
+      .collect();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Self {
+      root: project.path.display().to_string(),
+      template: relative_key(&project.template),
+      files,
+    }
+  }
+}
+
+impl DryRunFile {
+  fn kind(&self) -> &'static str {
+    if self.patched {
+      "patched"
+    } else if self.rendered {
+      "rendered"
+    } else {
+      "copied"
+    }
+  }
+}
+
+pub fn show_file<'a>(project: &'a GeneratedProject, path: &Path) -> Result<&'a [u8]> {
+  let key = relative_key(path);
+  let key = key.trim_start_matches("./");
+
+  project
+    .files
+    .iter()
+    .find(|file| relative_key(&file.path) == key)
+    .map(|file| file.contents.as_slice())
+    .ok_or_else(|| {
+      anyhow!(
+        "'{}' is not part of the generated project. Run with --dry-run alone to list files",
+        key
+      )
+    })
+}
+
+impl Render for DryRunReport {
+  fn to_table(&self) -> String {
+    let mut lines = vec![format!("{}/", self.root.trim_end_matches('/'))];
+    let mut parents: Vec<&str> = Vec::new();
+
+    for file in &self.files {
+      let segments: Vec<&str> = file.path.split('/').collect();
+      let (dirs, name) = segments.split_at(segments.len() - 1);
+
+      let common = parents.iter().zip(dirs).take_while(|(a, b)| a == b).count();
+      parents.truncate(common);
+
+      for dir in &dirs[common..] {
+        lines.push(format!("{}{}/", "  ".repeat(parents.len() + 1), dir));
+        parents.push(dir);
+      }
+
+      lines.push(format!(
+        "{}{}  ({}, {})",
+        "  ".repeat(parents.len() + 1),
+        name[0],
+        format_size(file.size),
+        file.kind()
+      ));
+    }
+
+    let count = |kind: &str| self.files.iter().filter(|f| f.kind() == kind).count();
+    lines.push(format!(
+      "\n{} files ({} rendered, {} copied, {} patched), {} total. Nothing was written.",
+      self.files.len(),
+      count("rendered"),
+      count("copied"),
+      count("patched"),
+      format_size(self.files.iter().map(|f| f.size).sum())
+    ));
+
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    self
+      .files
+      .iter()
+      .map(|f| format!("{}\t{}\t{}", f.kind(), f.size, f.path))
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
@@ -0,0 +1,629 @@
+use std::{
+  collections::BTreeMap,
+  fs,
+  path::{Path, PathBuf},
+  str::FromStr,
+  sync::Arc,
+};
+
+use anyhow::{Result, anyhow, bail};
+use clap::ValueEnum;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use tokio::process::Command;
+
+use crate::{
+  config::OxideConfig,
+  error::OxideError,
+  paths::OxidePaths,
+  project::{
+    GeneratedProject, ProjectGenerator, TemplateSource,
+    conflict::{
+      ConflictHandler, ConflictResolution, FileConflict, FileConflictPolicy, WriteSummary,
+      write_files,
+    },
+    dir_name,
+    events::{EventSink, GeneratorEvent},
+    fullstack::{self, FullstackSummary, TYPES_PATH, patch_json},
+    install_dependencies,
+    metadata::{MemberRecord, ProjectMetadata, WorkspaceRecord},
+    patch::Patch,
+  },
+  prompts::PackageManager,
+  templates::generator::RenderedFile,
+  utils::{
+    cancel::CancelToken,
+    fs::{is_relative_normal, relative_key},
+  },
+};
+
+const DEV_SCRIPTS: &str = "dev:*";
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceTool {
+  #[value(alias = "turbo")]
+  Turborepo,
+  Nx,
+}
+
+#[derive(Clone)]
+pub struct MemberSpec {
+  pub path: PathBuf,
+  pub template: String,
+}
+
+pub struct WorkspaceMember {
+  pub path: PathBuf,
+  pub source: TemplateSource,
+  pub answers: BTreeMap<String, String>,
+}
+
+pub struct GeneratedWorkspace {
+  pub path: PathBuf,
+  pub package_manager: PackageManager,
+  pub files: Vec<RenderedFile>,
+  pub summary: WriteSummary,
+  pub members: Vec<GeneratedProject>,
+  pub fullstack: Option<FullstackSummary>,
+}
+
+pub struct WorkspaceGenerator {
+  name: String,
+  package_manager: PackageManager,
+  output_dir: PathBuf,
+  tool: Option<WorkspaceTool>,
+  members: Vec<WorkspaceMember>,
+  fullstack: Option<(PathBuf, PathBuf)>,
+  install: bool,
+  conflict_policy: Option<FileConflictPolicy>,
+  conflict_handler: Option<ConflictHandler>,
+  paths: Option<OxidePaths>,
+  config: OxideConfig,
+  cancel: CancelToken,
+  events: EventSink,
+}
+
+impl FromStr for MemberSpec {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value.split_once('=') {
+      Some((path, template)) if !path.is_empty() && !template.is_empty() => Ok(Self {
+        path: PathBuf::from(path),
+        template: template.to_string(),
+      }),
+      _ => Err(format!(
+        "expected DIR=TEMPLATE (e.g. apps/web=react-vite), got '{}'",
+        value
+      )),
+    }
+  }
+}
+
+impl WorkspaceMember {
+  pub fn new(path: impl Into<PathBuf>, source: TemplateSource) -> Self {
+    Self {
+      path: path.into(),
+      source,
+      answers: BTreeMap::new(),
+    }
+  }
+
+  pub fn answer(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.answers.insert(name.into(), value.into());
+    self
+  }
+}
+
+impl WorkspaceGenerator {
+  pub fn new(name: impl Into<String>, package_manager: PackageManager) -> Self {
+    Self {
+      name: name.into(),
+      package_manager,
+      output_dir: PathBuf::new(),
+      tool: None,
+      members: Vec::new(),
+      fullstack: None,
+      install: false,
+      conflict_policy: None,
+      conflict_handler: None,
+      paths: None,
+      config: OxideConfig::default(),
+      cancel: CancelToken::new(),
+      events: EventSink::default(),
+    }
+  }
+
+  pub fn output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+    self.output_dir = output_dir.into();
+    self
+  }
+
+  pub fn tool(mut self, tool: Option<WorkspaceTool>) -> Self {
+    self.tool = tool;
+    self
+  }
+
+  pub fn member(mut self, member: WorkspaceMember) -> Self {
+    self.members.push(member);
+    self
+  }
+
+  pub fn fullstack(mut self, web: WorkspaceMember, api: WorkspaceMember) -> Self {
+    self.fullstack = Some((web.path.clone(), api.path.clone()));
+    self.members.push(web);
+    self.members.push(api);
+    self
+  }
+
+  pub fn install(mut self, install: bool) -> Self {
+    self.install = install;
+    self
+  }
+
+  pub fn conflict_policy(mut self, policy: Option<FileConflictPolicy>) -> Self {
+    self.conflict_policy = policy;
+    self
+  }
+
+  pub fn on_conflict(
+    mut self,
+    handler: impl Fn(&FileConflict) -> Result<ConflictResolution> + Send + Sync + 'static,
+  ) -> Self {
+    self.conflict_handler = Some(Arc::new(handler));
+    self
+  }
+
+  pub fn paths(mut self, paths: OxidePaths) -> Self {
+    self.paths = Some(paths);
+    self
+  }
+
+  pub fn config(mut self, config: OxideConfig) -> Self {
+    self.config = config;
+    self
+  }
+
+  pub fn cancel_token(mut self, cancel: CancelToken) -> Self {
+    self.cancel = cancel;
+    self
+  }
+
+  pub fn events(mut self, events: EventSink) -> Self {
+    self.events = events;
+    self
+  }
+
+  pub async fn generate(self) -> Result<GeneratedWorkspace> {
+    let paths = match &self.paths {
+      Some(paths) => paths.clone(),
+      None => OxidePaths::new()?,
+    };
+    paths.ensure_directories()?;
+
+    let root = self.output_dir.join(&self.name);
+    let name = match self.name.as_str() {
+      "." => dir_name(&root)?,
+      name => name.to_string(),
+    };
+
+    for (index, member) in self.members.iter().enumerate() {
+      check_member_path(&member.path)?;
+      if self.fullstack.is_some() && member.path == Path::new(TYPES_PATH) {
+        bail!("{} is reserved for the shared API types", TYPES_PATH);
+      }
+      if self.members[..index].iter().any(|m| m.path == member.path) {
+        bail!(
+          "Workspace member '{}' is listed more than once",
+          member.path.display()
+        );
+      }
+    }
+
+    if self.install {
+      which::which(self.package_manager.to_string())
+        .map_err(|_| OxideError::PackageManagerMissing(self.package_manager.to_string()))?;
+    }
+
+    let packages = package_globs(self.members.iter().map(|m| m.path.as_path()));
+    let version = package_manager_version(self.package_manager).await;
+    let mut files = root_files(
+      &name,
+      self.package_manager,
+      version.as_deref(),
+      self.tool,
+      &packages,
+    )?;
+    if self.fullstack.is_some() {
+      files.extend(fullstack::types_files(&name)?);
+    }
+
+    let created = !root.exists();
+    let written = self.write(&root, &name, &files, packages, &paths).await;
+
+    let installed = match (&written, self.install) {
+      (Ok(_), true) => {
+        install_dependencies(&self.package_manager, &root, &self.cancel, &self.events)
+          .await
+          .map(Some)
+      }
+      _ => Ok(None),
+    };
+
+    if created && root.exists() && (written.is_err() || self.cancel.is_cancelled()) {
+      fs::remove_dir_all(&root)?;
+      self
+        .events
+        .emit(GeneratorEvent::CleanedUp { path: root.clone() });
+    }
+
+    let (summary, members, fullstack) = written?;
+    if let Some(status) = installed?
+      && !status.success()
+    {
+      return Err(anyhow!(
+        "{} install failed with code {:?}",
+        self.package_manager,
+        status.code()
+      ));
+    }
+
+    Ok(GeneratedWorkspace {
+      path: root,
+      package_manager: self.package_manager,
+      files,
+      summary,
+      members,
+      fullstack,
+    })
+  }
+
+  async fn write(
+    &self,
+    root: &Path,
+    name: &str,
+    files: &[RenderedFile],
+    packages: Vec<String>,
+    paths: &OxidePaths,
+  ) -> Result<(
+    WriteSummary,
+    Vec<GeneratedProject>,
+    Option<FullstackSummary>,
+  )> {
+    let summary = write_files(
+      root,
+      files,
+      self.conflict_policy,
+      self.conflict_handler.as_ref(),
+      &self.events,
+    )?;
+
+    let mut members = Vec::new();
+    let mut records = Vec::new();
+    for member in &self.members {
+      self.cancel.check()?;
+      self.events.emit(GeneratorEvent::MemberStarted {
+        path: member.path.clone(),
+      });
+
+      let project = self.generate_member(root, member, paths).await?;
+      add_dev_script(root, &member.path, &self.events)?;
+      records.push(MemberRecord {
+        path: relative_key(&member.path),
+        template: Some(relative_key(&project.template)),
+      });
+      members.push(project);
+    }
+
+    let fullstack = match &self.fullstack {
+      Some((web, api)) => {
+        records.push(MemberRecord {
+          path: TYPES_PATH.to_string(),
+          template: None,
+        });
+        Some(fullstack::wire(
+          root,
+          name,
+          self.package_manager,
+          web,
+          api,
+          &self.events,
+        )?)
+      }
+      None => None,
+    };
+
+    let mut metadata = ProjectMetadata::load(root)?.unwrap_or_default();
+    metadata.name = name.to_string();
+    metadata.package_manager = Some(self.package_manager);
+    metadata.workspace = Some(WorkspaceRecord {
+      tool: self.tool,
+      packages,
+      members: records,
+    });
+    metadata.save(root)?;
+
+    Ok((summary, members, fullstack))
+  }
+
+  async fn generate_member(
+    &self,
+    root: &Path,
+    member: &WorkspaceMember,
+    paths: &OxidePaths,
+  ) -> Result<GeneratedProject> {
+    let (parent, name) = split_member_path(&member.path)?;
+    debug!("Generating workspace member {}", member.path.display());
+
+    let mut generator = ProjectGenerator::new(member.source.clone(), name)
+      .output_dir(root.join(parent))
+      .paths(paths.clone())
+      .config(self.config.clone())
+      .cancel_token(self.cancel.clone())
+      .conflict_policy(self.conflict_policy)
+      .events(self.events.clone());
+    generator.conflict_handler = self.conflict_handler.clone();
+    generator.answers.extend(member.answers.clone());
+
+    generator.generate().await
+  }
+}
+
+pub fn check_member_path(path: &Path) -> Result<()> {
+  if !is_relative_normal(path) {
+    bail!(
+      "Workspace member '{}' must be a relative path inside the workspace, e.g. apps/web",
+      path.display()
+    );
+  }
+  Ok(())
+}
+
+pub(crate) fn split_member_path(path: &Path) -> Result<(PathBuf, String)> {
+  let name = path
+    .file_name()
+    .map(|name| name.to_string_lossy().to_string())
+    .ok_or_else(|| anyhow!("'{}' has no directory name", path.display()))?;
+  let parent = path.parent().unwrap_or(Path::new("")).to_path_buf();
+  Ok((parent, name))
+}
+
+pub(crate) fn package_globs<'a>(members: impl Iterator<Item = &'a Path>) -> Vec<String> {
+  let mut globs = vec!["apps/*".to_string(), "packages/*".to_string()];
+
+  for member in members {
+    let glob = match member.parent().filter(|p| !p.as_os_str().is_empty()) {
+      Some(parent) => format!("{}/*", relative_key(parent)),
+      None => relative_key(member),
+    };
+    if !globs.contains(&glob) {
+      globs.push(glob);
+    }
+  }
+
+  globs
+}
+
+pub(crate) fn add_dev_script(root: &Path, member: &Path, events: &EventSink) -> Result<()> {
+  let root_package = root.join("package.json");
+  let member_package = root.join(member).join("package.json");
+  if !root_package.exists() || !member_package.exists() {
+    return Ok(());
+  }
+
+  let scripts = serde_json::from_slice::<Value>(&fs::read(&root_package)?)?
+    .get("scripts")
+    .cloned()
+    .unwrap_or_default();
+  let dev = scripts
+    .get("dev")
+    .and_then(Value::as_str)
+    .unwrap_or_default();
+  let key = relative_key(member);
+  let command = if dev.contains(&format!("\"npm:{}\"", DEV_SCRIPTS)) {
+    format!("npm run dev -w {}", key)
+  } else if dev.contains(&format!("\"yarn:{}\"", DEV_SCRIPTS)) {
+    format!("yarn --cwd {} run dev", key)
+  } else {
+    return Ok(());
+  };
+
+  let has_dev = serde_json::from_slice::<Value>(&fs::read(&member_package)?)?
+    .pointer("/scripts/dev")
+    .is_some();
+  if !has_dev {
+    debug!("{} has no dev script", member.display());
+    return Ok(());
+  }
+
+  let (_, name) = split_member_path(member)?;
+  let mut script = format!("dev:{}", name);
+  if scripts
+    .get(&script)
+    .is_some_and(|existing| existing != &json!(command))
+  {
+    script = format!("dev:{}", key.replace('/', "-"));
+  }
+
+  patch_json(
+    &root_package,
+    Patch {
+      merge: Some(json!({ "scripts": { script: command } })),
+      ..Default::default()
+    },
+    events,
+  )
+}
+
+async fn package_manager_version(package_manager: PackageManager) -> Option<String> {
+  let output = Command::new(package_manager.to_string())
+    .arg("--version")
+    .output()
+    .await
+    .ok()?;
+  if !output.status.success() {
+    return None;
+  }
+
+  let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+  semver::Version::parse(&version).ok()?;
+  Some(version)
+}
+
+fn is_yarn_classic(version: Option<&str>) -> bool {
+  version
+    .and_then(|v| semver::Version::parse(v).ok())
+    .is_none_or(|v| v.major < 2)
+}
+
+fn runs_sequentially(
+  package_manager: PackageManager,
+  tool: Option<WorkspaceTool>,
+  yarn_classic: bool,
+) -> bool {
+  tool.is_none()
+    && match package_manager {
+      PackageManager::NPM => true,
+      PackageManager::Yarn => yarn_classic,
+      PackageManager::PNPM | PackageManager::Bun => false,
+    }
+}
+
+fn run_script(
+  package_manager: PackageManager,
+  tool: Option<WorkspaceTool>,
+  yarn_classic: bool,
+  task: &str,
+) -> String {
+  match (tool, package_manager) {
+    (Some(WorkspaceTool::Turborepo), _) => format!("turbo run {}", task),
+    (Some(WorkspaceTool::Nx), _) => format!("nx run-many -t {}", task),
+    (None, _) if task == "dev" && runs_sequentially(package_manager, tool, yarn_classic) => {
+      format!("concurrently -k \"{}:{}\"", package_manager, DEV_SCRIPTS)
+    }
+    (None, PackageManager::PNPM) => format!("pnpm -r --parallel run {}", task),
+    (None, PackageManager::Yarn) if yarn_classic => format!("yarn workspaces run {}", task),
+    (None, PackageManager::Yarn) => {
+      format!(
+        "yarn workspaces foreach --all --parallel --interlaced run {}",
+        task
+      )
+    }
+    (None, PackageManager::Bun) => format!("bun run --filter '*' {}", task),
+    (None, PackageManager::NPM) => format!("npm run {} --workspaces --if-present", task),
+  }
+}
+
+fn json_file(path: &str, value: &Value) -> Result<RenderedFile> {
+  Ok(RenderedFile {
+    path: PathBuf::from(path),
+    contents: format!("{}\n", serde_json::to_string_pretty(value)?).into_bytes(),
+    rendered: true,
+  })
+}
+
+fn root_files(
+  name: &str,
+  package_manager: PackageManager,
+  version: Option<&str>,
+  tool: Option<WorkspaceTool>,
+  packages: &[String],
+) -> Result<Vec<RenderedFile>> {
+  let mut package = Map::new();
+  package.insert("name".to_string(), json!(name));
+  package.insert("private".to_string(), json!(true));
+  if let Some(version) = version {
+    package.insert(
+      "packageManager".to_string(),
+      json!(format!("{}@{}", package_manager, version)),
+    );
+  }
+  if package_manager != PackageManager::PNPM {
+    package.insert("workspaces".to_string(), json!(packages));
+  }
+
+  let yarn_classic = package_manager == PackageManager::Yarn && is_yarn_classic(version);
+  let scripts: Map<String, Value> = ["dev", "build", "lint"]
+    .into_iter()
+    .map(|task| {
+      (
+        task.to_string(),
+        json!(run_script(package_manager, tool, yarn_classic, task)),
+      )
+    })
+    .collect();
+  package.insert("scripts".to_string(), Value::Object(scripts));
+
+  match tool {
+    Some(WorkspaceTool::Turborepo) => {
+      package.insert("devDependencies".to_string(), json!({ "turbo": "^2.3.0" }));
+    }
+    Some(WorkspaceTool::Nx) => {
+      package.insert("devDependencies".to_string(), json!({ "nx": "^20.0.0" }));
+    }
+    None if runs_sequentially(package_manager, tool, yarn_classic) => {
+      package.insert(
+        "devDependencies".to_string(),
+        json!({ "concurrently": "^9.1.0" }),
+      );
+    }
+    None => {}
+  }
+
+  let mut files = vec![json_file("package.json", &Value::Object(package))?];
+
+  match package_manager {
+    PackageManager::PNPM => files.push(RenderedFile {
+      path: PathBuf::from("pnpm-workspace.yaml"),
+      contents: serde_yaml::to_string(&json!({ "packages": packages }))?.into_bytes(),
+      rendered: true,
+    }),
+    PackageManager::Yarn if !yarn_classic => files.push(RenderedFile {
+      path: PathBuf::from(".yarnrc.yml"),
+      contents: b"nodeLinker: node-modules\n".to_vec(),
+      rendered: true,
+    }),
+    PackageManager::Yarn | PackageManager::NPM | PackageManager::Bun => {}
+  }
+
+  match tool {
+    Some(WorkspaceTool::Turborepo) => files.push(json_file(
+      "turbo.json",
+      &json!({
+        "$schema": "https://turbo.build/schema.json",
+        "tasks": {
+          "build": { "dependsOn": ["^build"], "outputs": ["dist/**", ".next/**", "!.next/cache/**"] },
+          "dev": { "cache": false, "persistent": true },
+          "lint": { "dependsOn": ["^lint"] }
+        }
+      }),
+    )?),
+    Some(WorkspaceTool::Nx) => files.push(json_file(
+      "nx.json",
+      &json!({
+        "$schema": "./node_modules/nx/schemas/nx-schema.json",
+        "targetDefaults": {
+          "build": { "dependsOn": ["^build"], "outputs": ["{projectRoot}/dist"], "cache": true },
+          "lint": { "cache": true }
+        }
+      }),
+    )?),
+    None => {}
+  }
+
+  let mut ignored = vec!["node_modules", "dist", ".env*.local"];
+  match tool {
+    Some(WorkspaceTool::Turborepo) => ignored.push(".turbo"),
+    Some(WorkspaceTool::Nx) => ignored.extend([".nx/cache", ".nx/workspace-data"]),
+    None => {}
+  }
+  files.push(RenderedFile {
+    path: PathBuf::from(".gitignore"),
+    contents: format!("{}\n", ignored.join("\n")).into_bytes(),
+    rendered: true,
+  });
+
+  Ok(files)
+}
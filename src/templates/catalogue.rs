@@ -0,0 +1,282 @@
+use std::{fmt, path::PathBuf};
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::prompts::Language;
+
+const EMBEDDED_CATALOGUE: &str = include_str!("catalogue.json");
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Catalogue {
+  pub layers: Vec<CatalogueLayer>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CatalogueLayer {
+  pub id: String,
+  pub name: String,
+  pub frameworks: Vec<CatalogueFramework>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CatalogueFramework {
+  pub id: String,
+  pub name: String,
+  #[serde(default)]
+  pub aliases: Vec<String>,
+  #[serde(rename = "buildTools", default)]
+  pub build_tools: Vec<String>,
+  #[serde(default = "default_languages")]
+  pub languages: Vec<Language>,
+  #[serde(default)]
+  pub variants: Vec<CatalogueVariant>,
+  #[serde(rename = "askUserName", default)]
+  pub ask_user_name: bool,
+  pub path: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CatalogueVariant {
+  pub id: String,
+  pub name: String,
+  #[serde(rename = "buildTools", default)]
+  pub build_tools: Vec<String>,
+}
+
+#[derive(Clone, Default)]
+pub struct TemplateSelection {
+  pub layer: Option<String>,
+  pub framework: String,
+  pub build_tool: Option<String>,
+  pub language: Option<Language>,
+  pub platform: Option<String>,
+}
+
+fn default_languages() -> Vec<Language> {
+  vec![Language::TypeScript]
+}
+
+fn options<'a>(names: impl Iterator<Item = &'a str>) -> String {
+  names.collect::<Vec<_>>().join(", ")
+}
+
+impl Catalogue {
+  pub fn embedded() -> Self {
+    serde_json::from_str(EMBEDDED_CATALOGUE).expect("embedded catalogue is valid JSON")
+  }
+
+  pub fn find_layer(&self, name: &str) -> Result<&CatalogueLayer> {
+    self
+      .layers
+      .iter()
+      .find(|l| l.id.eq_ignore_ascii_case(name) || l.name.eq_ignore_ascii_case(name))
+      .ok_or_else(|| {
+        anyhow!(
+          "Unknown layer '{}'. Available layers: {}",
+          name,
+          options(self.layers.iter().map(|l| l.id.as_str()))
+        )
+      })
+  }
+
+  pub fn resolve(&self, selection: &TemplateSelection) -> Result<PathBuf> {
+    let framework = match &selection.layer {
+      Some(layer) => self
+        .find_layer(layer)?
+        .find_framework(&selection.framework)?,
+      None => self.find_framework(&selection.framework)?.1,
+    };
+
+    let build_tool = match &selection.build_tool {
+      Some(b) => Some(framework.find_build_tool(b)?),
+      None if !framework.build_tools.is_empty() => bail!(
+        "{} requires a build tool: {}",
+        framework.name,
+        options(framework.build_tools.iter().map(|b| b.as_str()))
+      ),
+      None => None,
+    };
+
+    let language = match selection.language {
+      Some(l) => framework.check_language(l)?,
+      None => framework.default_language(),
+    };
+
+    let variants = framework.variants_for(build_tool);
+    let platform = match &selection.platform {
+      Some(p) => Some(framework.find_variant(build_tool, p)?),
+      None if !variants.is_empty() => bail!(
+        "{} requires a platform: {}",
+        framework.name,
+        options(variants.iter().map(|v| v.id.as_str()))
+      ),
+      None => None,
+    };
+
+    Ok(framework.template_path(language, build_tool, platform))
+  }
+
+  pub fn find_framework(&self, name: &str) -> Result<(&CatalogueLayer, &CatalogueFramework)> {
+    self
+      .layers
+      .iter()
+      .find_map(|layer| {
+        layer
+          .frameworks
+          .iter()
+          .find(|f| f.matches(name))
+          .map(|framework| (layer, framework))
+      })
+      .ok_or_else(|| anyhow!("Unknown framework '{}'", name))
+  }
+}
+
+impl CatalogueLayer {
+  pub fn find_framework(&self, name: &str) -> Result<&CatalogueFramework> {
+    self
+      .frameworks
+      .iter()
+      .find(|f| f.matches(name))
+      .ok_or_else(|| {
+        anyhow!(
+          "Unknown {} framework '{}'. Available frameworks: {}",
+          self.name.to_lowercase(),
+          name,
+          options(self.frameworks.iter().map(|f| f.id.as_str()))
+        )
+      })
+  }
+}
+
+impl CatalogueFramework {
+  pub fn matches(&self, name: &str) -> bool {
+    self.id.eq_ignore_ascii_case(name)
+      || self.name.eq_ignore_ascii_case(name)
+      || self.name.replace(' ', "").eq_ignore_ascii_case(name)
+      || self.aliases.iter().any(|a| a.eq_ignore_ascii_case(name))
+  }
+
+  pub fn find_build_tool(&self, name: &str) -> Result<&str> {
+    if self.build_tools.is_empty() {
+      bail!("{} does not use a build tool", self.name);
+    }
+
+    self
+      .build_tools
+      .iter()
+      .find(|b| b.eq_ignore_ascii_case(name))
+      .map(|b| b.as_str())
+      .ok_or_else(|| {
+        anyhow!(
+          "{} does not support build tool '{}'. Available build tools: {}",
+          self.name,
+          name,
+          options(self.build_tools.iter().map(|b| b.as_str()))
+        )
+      })
+  }
+
+  pub fn default_language(&self) -> Language {
+    self
+      .languages
+      .first()
+      .copied()
+      .unwrap_or(Language::TypeScript)
+  }
+
+  pub fn check_language(&self, language: Language) -> Result<Language> {
+    if self.languages.contains(&language) {
+      return Ok(language);
+    }
+
+    bail!(
+      "{} does not support {}. Available languages: {}",
+      self.name,
+      language,
+      self
+        .languages
+        .iter()
+        .map(|l| l.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+    )
+  }
+
+  pub fn variants_for(&self, build_tool: Option<&str>) -> Vec<&CatalogueVariant> {
+    self
+      .variants
+      .iter()
+      .filter(|v| {
+        v.build_tools.is_empty()
+          || build_tool.is_some_and(|b| v.build_tools.iter().any(|t| t.eq_ignore_ascii_case(b)))
+      })
+      .collect()
+  }
+
+  pub fn find_variant(&self, build_tool: Option<&str>, name: &str) -> Result<&CatalogueVariant> {
+    let variants = self.variants_for(build_tool);
+
+    if variants.is_empty() {
+      bail!(
+        "{}{} has no platform variants",
+        self.name,
+        build_tool
+          .map(|b| format!(" with {}", b))
+          .unwrap_or_default()
+      );
+    }
+
+    variants
+      .iter()
+      .find(|v| v.id.eq_ignore_ascii_case(name) || v.name.eq_ignore_ascii_case(name))
+      .copied()
+      .ok_or_else(|| {
+        anyhow!(
+          "Unknown platform '{}' for {}. Available platforms: {}",
+          name,
+          self.name,
+          options(variants.iter().map(|v| v.id.as_str()))
+        )
+      })
+  }
+
+  pub fn template_path(
+    &self,
+    language: Language,
+    build_tool: Option<&str>,
+    variant: Option<&CatalogueVariant>,
+  ) -> PathBuf {
+    let resolved = self
+      .path
+      .replace("{language}", language.id())
+      .replace(
+        "{buildTool}",
+        &build_tool.unwrap_or_default().to_lowercase(),
+      )
+      .replace(
+        "{variant}",
+        variant.map(|v| v.id.as_str()).unwrap_or_default(),
+      );
+
+    resolved.split('/').filter(|s| !s.is_empty()).collect()
+  }
+}
+
+impl fmt::Display for CatalogueLayer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name)
+  }
+}
+
+impl fmt::Display for CatalogueFramework {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name)
+  }
+}
+
+impl fmt::Display for CatalogueVariant {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name)
+  }
+}
@@ -0,0 +1,236 @@
+use std::{
+  collections::BTreeMap,
+  fs,
+  path::{Path, PathBuf},
+};
+
+use anyhow::{Result, bail};
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  cache::{find_cached_template, load_templates_cache},
+  config::OxideConfig,
+  error::OxideError,
+  output::Render,
+  paths::OxidePaths,
+  project::{events::EventSink, patch::patch_target},
+  templates::{TemplateFile, install::install_template, registry::get_registry},
+  utils::{
+    cancel::CancelToken,
+    fs::{read_dir_to_files, relative_key},
+  },
+};
+
+#[derive(Serialize)]
+pub struct TemplateTree {
+  pub name: String,
+  pub path: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub extends: Vec<TemplateTree>,
+}
+
+#[derive(Deserialize)]
+struct ManifestExtends {
+  #[serde(default)]
+  extends: Vec<String>,
+}
+
+pub async fn resolve_template_tree(
+  oxide_paths: &OxidePaths,
+  path: &Path,
+  config: &OxideConfig,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<TemplateTree> {
+  let mut stack = Vec::new();
+  resolve_node(oxide_paths, path, config, cancel, events, &mut stack).await
+}
+
+async fn resolve_node(
+  oxide_paths: &OxidePaths,
+  path: &Path,
+  config: &OxideConfig,
+  cancel: &CancelToken,
+  events: &EventSink,
+  stack: &mut Vec<String>,
+) -> Result<TemplateTree> {
+  let template_path = &oxide_paths.templates;
+  let cached = match find_cached_template(template_path, path)? {
+    Some(cached) => cached,
+    None => {
+      install_template(template_path, path, config, cancel, events).await?;
+      find_cached_template(template_path, path)?
+        .ok_or_else(|| OxideError::TemplateNotFound(relative_key(path)))?
+    }
+  };
+
+  if stack.contains(&cached.name) {
+    stack.push(cached.name);
+    bail!("Template inheritance cycle: {}", stack.join(" → "));
+  }
+  stack.push(cached.name.clone());
+
+  let mut extends = Vec::new();
+  for parent in read_extends(&template_path.join(path))? {
+    debug!("{} extends {}", cached.name, parent);
+    let parent_path = resolve_template_name(oxide_paths, &parent, config).await?;
+    extends.push(
+      Box::pin(resolve_node(
+        oxide_paths,
+        &parent_path,
+        config,
+        cancel,
+        events,
+        stack,
+      ))
+      .await?,
+    );
+  }
+
+  stack.pop();
+  Ok(TemplateTree {
+    name: cached.name,
+    path: relative_key(path),
+    extends,
+  })
+}
+
+fn read_extends(template_dir: &Path) -> Result<Vec<String>> {
+  let manifest_path = template_dir.join("oxide.template.json");
+  if !manifest_path.exists() {
+    return Ok(Vec::new());
+  }
+
+  let content = fs::read_to_string(&manifest_path)?;
+  let manifest: ManifestExtends =
+    serde_json::from_str(&content).map_err(|source| OxideError::InvalidManifest {
+      path: manifest_path.display().to_string(),
+      source,
+    })?;
+  Ok(manifest.extends)
+}
+
+pub async fn resolve_template_name(
+  oxide_paths: &OxidePaths,
+  name: &str,
+  config: &OxideConfig,
+) -> Result<PathBuf> {
+  let cached = load_templates_cache(&oxide_paths.templates)?
+    .templates
+    .into_iter()
+    .find(|t| t.name == name && oxide_paths.templates.join(&t.path).exists());
+  if let Some(cached) = cached {
+    return Ok(PathBuf::from(cached.path));
+  }
+
+  get_registry(&oxide_paths.registry, config)
+    .await?
+    .into_iter()
+    .find(|t| t.name == name)
+    .map(|t| PathBuf::from(t.path))
+    .ok_or_else(|| OxideError::TemplateNotFound(name.to_string()).into())
+}
+
+impl TemplateTree {
+  pub fn layers(&self) -> Vec<&TemplateTree> {
+    let mut layers = Vec::new();
+    self.collect_layers(&mut layers);
+    layers
+  }
+
+  fn collect_layers<'a>(&'a self, layers: &mut Vec<&'a TemplateTree>) {
+    for parent in &self.extends {
+      parent.collect_layers(layers);
+    }
+    if !layers.iter().any(|l| l.path == self.path) {
+      layers.push(self);
+    }
+  }
+}
+
+pub fn layer_files(template_path: &Path, tree: &TemplateTree) -> Result<Vec<TemplateFile>> {
+  let mut files: BTreeMap<PathBuf, TemplateFile> = BTreeMap::new();
+  let mut patches: Vec<(usize, PathBuf, TemplateFile)> = Vec::new();
+
+  for (index, layer) in tree.layers().iter().enumerate() {
+    let layer_dir = template_path.join(&layer.path);
+    for file in read_dir_to_files(&layer_dir)? {
+      if let Some((target, _)) = patch_target(&output_name(&file.path)) {
+        patches.push((index, target, file));
+        continue;
+      }
+
+      let name = output_name(&file.path);
+      patches.retain(|(layer_index, target, patch)| {
+        let keep = *layer_index == index || *target != name;
+        if !keep {
+          debug!(
+            "{} overrides {}, skipping {}",
+            layer.name,
+            relative_key(&name),
+            relative_key(&patch.path)
+          );
+        }
+        keep
+      });
+
+      if let Some(overridden) = files.insert(name, file) {
+        debug!(
+          "{} overrides {}",
+          layer.name,
+          relative_key(&overridden.path)
+        );
+      }
+    }
+  }
+
+  Ok(
+    files
+      .into_values()
+      .chain(patches.into_iter().map(|(_, _, file)| file))
+      .collect(),
+  )
+}
+
+fn output_name(path: &Path) -> PathBuf {
+  match path.to_str().and_then(|p| p.strip_suffix(".tera")) {
+    Some(stripped) => PathBuf::from(stripped),
+    None => path.to_path_buf(),
+  }
+}
+
+impl Render for TemplateTree {
+  fn to_table(&self) -> String {
+    let mut lines = vec![format!("{} ({})", self.name, self.path)];
+    render_children(&self.extends, "", &mut lines);
+
+    let order: Vec<&str> = self.layers().iter().map(|l| l.name.as_str()).collect();
+    lines.push(format!("\nLayering order: {}", order.join(" → ")));
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    self
+      .layers()
+      .iter()
+      .map(|l| format!("{}\t{}", l.name, l.path))
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+fn render_children(children: &[TemplateTree], prefix: &str, lines: &mut Vec<String>) {
+  for (index, child) in children.iter().enumerate() {
+    let last = index == children.len() - 1;
+    lines.push(format!(
+      "{}{} {} ({})",
+      prefix,
+      if last { "└──" } else { "├──" },
+      child.name,
+      child.path
+    ));
+    let prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
+    render_children(&child.extends, &prefix, lines);
+  }
+}
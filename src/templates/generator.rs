@@ -1,33 +1,44 @@
 use std::{
-  fs,
+  collections::BTreeMap,
   path::{Path, PathBuf},
 };
 
 use anyhow::Result;
+use log::debug;
 use tera::{Context, Tera};
 
-use crate::templates::TemplateFile;
+use crate::{
+  error::OxideError,
+  project::events::{EventSink, GeneratorEvent},
+  templates::TemplateFile,
+  utils::cancel::CancelToken,
+};
+
+pub struct RenderedFile {
+  pub path: PathBuf,
+  pub contents: Vec<u8>,
+  pub rendered: bool,
+}
 
 pub fn extract_template(
   files: &[TemplateFile],
+  output_path: &Path,
   project_name: &str,
-  tauri_user_name: Option<String>,
-) -> Result<()> {
-  let output_path = PathBuf::from(project_name);
-  fs::create_dir_all(&output_path)?;
-
+  variables: &BTreeMap<String, String>,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<Vec<RenderedFile>> {
   let mut context = Context::new();
+  for (name, value) in variables {
+    context.insert(name, value);
+  }
   context.insert("project_name", project_name);
   context.insert("project_name_kebab", &to_kebab_case(project_name));
   context.insert("project_name_snake", &to_snake_case(project_name));
-  let user_name = tauri_user_name.unwrap_or("tauri".to_string());
-  context.insert("tauri_user_name", &user_name);
 
   let mut tera = Tera::default();
 
-  extract_dir_contents(files, &output_path, &mut tera, &context)?;
-
-  Ok(())
+  extract_dir_contents(files, output_path, &mut tera, &context, cancel, events)
 }
 
 fn to_kebab_case(s: &str) -> String {
@@ -55,30 +66,55 @@ pub fn extract_dir_contents(
   base_path: &Path,
   tera: &mut Tera,
   context: &Context,
-) -> Result<()> {
-  for file in files {
-    let file_name = file.path.file_name().unwrap();
-    let file_name_str = file_name.to_string_lossy();
-
-    let output_path = base_path.join(&file.path);
-    if let Some(parent) = output_path.parent() {
-      fs::create_dir_all(parent)?;
-    }
-
-    if file_name_str.ends_with(".tera") {
-      let output_name = file_name_str.trim_end_matches(".tera");
-      let output_path = output_path.with_file_name(output_name);
-
-      let template_content = std::str::from_utf8(&file.contents)?;
-      tera.add_raw_template(&file_name_str, template_content)?;
-      let rendered = tera.render(&file_name_str, context)?;
-
-      fs::write(&output_path, rendered)?;
-      println!("  ✓ {}", output_path.display());
-    } else {
-      fs::write(&output_path, &file.contents)?;
-      println!("  ✓ {}", output_path.display());
-    }
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<Vec<RenderedFile>> {
+  events.emit(GeneratorEvent::RenderStarted { files: files.len() });
+
+  let result = files
+    .iter()
+    .map(|file| {
+      cancel.check()?;
+
+      let rendered = render_file(file, tera, context)?;
+      let output_path = base_path.join(&rendered.path);
+
+      debug!("Rendered {}", output_path.display());
+      events.emit(GeneratorEvent::FileRendered { path: output_path });
+      Ok(rendered)
+    })
+    .collect();
+
+  events.emit(GeneratorEvent::RenderFinished);
+  result
+}
+
+fn render_file(file: &TemplateFile, tera: &mut Tera, context: &Context) -> Result<RenderedFile> {
+  let file_name = file.path.file_name().unwrap();
+  let file_name_str = file_name.to_string_lossy();
+
+  if file_name_str.ends_with(".tera") {
+    let output_name = file_name_str.trim_end_matches(".tera");
+
+    let template_content = std::str::from_utf8(&file.contents)?;
+    let template_file = file.path.display().to_string();
+    tera
+      .add_raw_template(&file_name_str, template_content)
+      .map_err(|e| OxideError::render(&template_file, e))?;
+    let rendered = tera
+      .render(&file_name_str, context)
+      .map_err(|e| OxideError::render(&template_file, e))?;
+
+    Ok(RenderedFile {
+      path: file.path.with_file_name(output_name),
+      contents: rendered.into_bytes(),
+      rendered: true,
+    })
+  } else {
+    Ok(RenderedFile {
+      path: file.path.clone(),
+      contents: file.contents.clone(),
+      rendered: false,
+    })
   }
-  Ok(())
 }
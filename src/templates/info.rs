@@ -0,0 +1,182 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::Result;
+use reqwest::header::USER_AGENT;
+use serde::Serialize;
+
+use crate::{
+  cache::load_templates_cache,
+  config::OxideConfig,
+  error::OxideError,
+  output::Render,
+  paths::OxidePaths,
+  templates::{OxideTemplate, registry::get_registry},
+  utils::{
+    fs::{dir_stats, format_size},
+    http::{build_client, send},
+  },
+};
+
+#[derive(Serialize)]
+pub struct TemplateInfo {
+  pub manifest: OxideTemplate,
+  pub installation: Option<TemplateInstallation>,
+}
+
+#[derive(Serialize)]
+pub struct TemplateInstallation {
+  pub path: PathBuf,
+  #[serde(rename = "installedAt")]
+  pub installed_at: Option<String>,
+  #[serde(rename = "fileCount")]
+  pub file_count: usize,
+  pub size: u64,
+}
+
+pub async fn get_template_info(
+  oxide_paths: &OxidePaths,
+  template_name: &str,
+  config: &OxideConfig,
+) -> Result<TemplateInfo> {
+  let templates_info = load_templates_cache(&oxide_paths.templates)?;
+
+  if let Some(cached) = templates_info
+    .templates
+    .iter()
+    .find(|t| t.name == template_name)
+  {
+    let install_path = oxide_paths.templates.join(&cached.path);
+    let manifest_path = install_path.join("oxide.template.json");
+    let content = fs::read_to_string(&manifest_path)?;
+    let manifest: OxideTemplate =
+      serde_json::from_str(&content).map_err(|source| OxideError::InvalidManifest {
+        path: manifest_path.display().to_string(),
+        source,
+      })?;
+    let (file_count, size) = dir_stats(&install_path);
+
+    return Ok(TemplateInfo {
+      manifest,
+      installation: Some(TemplateInstallation {
+        path: install_path,
+        installed_at: cached.installed_at.clone(),
+        file_count,
+        size,
+      }),
+    });
+  }
+
+  let registry = get_registry(&oxide_paths.registry, config).await?;
+  let entry = registry
+    .iter()
+    .find(|t| t.name == template_name)
+    .ok_or_else(|| OxideError::TemplateNotFound(template_name.to_string()))?;
+
+  config.ensure_online(&[format!("manifest for template '{}'", template_name)])?;
+  let manifest = fetch_manifest(&entry.path, config).await?;
+
+  Ok(TemplateInfo {
+    manifest,
+    installation: None,
+  })
+}
+
+async fn fetch_manifest(path: &str, config: &OxideConfig) -> Result<OxideTemplate> {
+  let client = build_client(config)?;
+  let raw_url = format!(
+    "https://raw.githubusercontent.com/oxide-cli/templates/main/{}/oxide.template.json",
+    path
+  );
+
+  let content = send(client.get(&raw_url).header(USER_AGENT, "oxide"))
+    .await?
+    .error_for_status()?
+    .text()
+    .await?;
+
+  Ok(
+    serde_json::from_str(&content).map_err(|source| OxideError::InvalidManifest {
+      path: raw_url,
+      source,
+    })?,
+  )
+}
+
+impl Render for TemplateInfo {
+  fn to_table(&self) -> String {
+    let manifest = &self.manifest;
+    let mut lines = vec![
+      format!("\n{} ({})", manifest.metadata.display_name, manifest.name),
+      format!("{}\n", manifest.metadata.description),
+      format!("  Version:        {}", manifest.version),
+      format!("  Oxide version:  {}", manifest.oxide_version),
+      format!("  Repository:     {}", manifest.repository.url),
+      format!(
+        "  Official:       {}",
+        if manifest.official { "✓" } else { "✗" }
+      ),
+    ];
+
+    match &self.installation {
+      Some(installation) => {
+        lines.push(format!("  Installed:      {}", installation.path.display()));
+        if let Some(installed_at) = &installation.installed_at {
+          lines.push(format!("  Installed at:   {}", installed_at));
+        }
+        lines.push(format!(
+          "  Files:          {} ({})",
+          installation.file_count,
+          format_size(installation.size)
+        ));
+      }
+      None => lines.push("  Installed:      ✗".to_string()),
+    }
+
+    if !manifest.variables.is_empty() {
+      lines.push("\nVariables:".to_string());
+      for variable in &manifest.variables {
+        let mut line = format!("  {}", variable.name);
+        if let Some(default) = &variable.default {
+          line.push_str(&format!(" (default: {})", default));
+        }
+        if let Some(description) = &variable.description {
+          line.push_str(&format!(" - {}", description));
+        }
+        lines.push(line);
+      }
+    }
+
+    if !manifest.hooks.is_empty() {
+      lines.push("\nHooks:".to_string());
+      for hook in &manifest.hooks {
+        lines.push(format!("  {}: {}", hook.event, hook.command));
+      }
+    }
+
+    lines.join("\n")
+  }
+
+  fn to_plain(&self) -> String {
+    let manifest = &self.manifest;
+    let mut lines = vec![
+      format!("name\t{}", manifest.name),
+      format!("displayName\t{}", manifest.metadata.display_name),
+      format!("description\t{}", manifest.metadata.description),
+      format!("version\t{}", manifest.version),
+      format!("oxideVersion\t{}", manifest.oxide_version),
+      format!("repository\t{}", manifest.repository.url),
+      format!("official\t{}", manifest.official),
+    ];
+
+    if let Some(installation) = &self.installation {
+      lines.push(format!("path\t{}", installation.path.display()));
+      if let Some(installed_at) = &installation.installed_at {
+        lines.push(format!("installedAt\t{}", installed_at));
+      }
+      lines.push(format!("fileCount\t{}", installation.file_count));
+      lines.push(format!("size\t{}", installation.size));
+    }
+
+    lines.join("\n")
+  }
+}
@@ -1,108 +1,123 @@
-use std::{
-  fs,
-  path::{Path, PathBuf},
-  time::Duration,
-};
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
-use reqwest::Client;
-use reqwest::header::USER_AGENT;
-use serde::{Deserialize, Serialize};
+use anyhow::{Result, bail};
+use log::{debug, info};
 
 use crate::{
-  cache::{is_template_installed, update_templates_cache},
-  utils::git::download_dir,
+  cache::{
+    find_cached_template, is_template_installed, lock::CacheLock, remove_template_dir,
+    update_templates_cache,
+  },
+  config::OxideConfig,
+  error::OxideError,
+  paths::OxidePaths,
+  project::events::{EventSink, GeneratorEvent},
+  templates::registry::get_registry,
+  utils::{
+    cancel::CancelToken,
+    fs::{is_relative_normal, relative_key},
+    git::download_dir,
+    http::build_client,
+  },
 };
 
-pub async fn install_template(template_path: &Path, path: &PathBuf) -> Result<()> {
-  let client = Client::builder().timeout(Duration::from_secs(30)).build()?;
+pub async fn install_template(
+  template_path: &Path,
+  path: &Path,
+  config: &OxideConfig,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<()> {
+  download_template(template_path, path, config, cancel, events).await?;
+  info!("✓ Template successfully downloaded");
+
+  Ok(())
+}
+
+pub async fn download_template(
+  template_path: &Path,
+  path: &Path,
+  config: &OxideConfig,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<()> {
+  if !is_relative_normal(path) {
+    bail!(
+      "Template path '{}' must be relative to the template cache",
+      path.display()
+    );
+  }
+
+  let _lock = CacheLock::template(template_path, path)?;
+
+  if find_cached_template(template_path, path)?.is_some() {
+    info!("✓ Template already downloaded");
+    events.emit(GeneratorEvent::TemplateCached {
+      path: path.to_path_buf(),
+    });
+    return Ok(());
+  }
+  debug!("Template {} is not cached, downloading", relative_key(path));
+
+  config.ensure_online(&[format!("template '{}'", relative_key(path))])?;
+  remove_template_dir(template_path, &template_path.join(path))?;
+
+  let client = build_client(config)?;
 
   let api_url = format!(
     "https://api.github.com/repos/oxide-cli/templates/contents/{}",
     path.to_str().unwrap_or_default()
   );
 
-  let cleanup_path = template_path.join(path);
-  let template_path_clone = template_path.to_path_buf();
-
-  ctrlc::set_handler(move || {
-    println!("\n⚠ Interrupted! Cleaning up...");
-    if cleanup_path.exists() {
-      if let Err(e) = fs::remove_dir_all(&cleanup_path) {
-        println!("Failed to remove: {}", e);
-      }
-
-      let mut current = cleanup_path.parent();
-      while let Some(parent) = current {
-        if parent == template_path_clone {
-          break;
-        }
-        if fs::remove_dir(parent).is_err() {
-          break;
-        }
-        current = parent.parent();
-      }
-      println!("✓ Removed incomplete template");
+  let install_path = template_path.join(path);
+  events.emit(GeneratorEvent::DownloadStarted {
+    path: path.to_path_buf(),
+  });
+  let downloaded = download_dir(&client, &api_url, &install_path, cancel, events)
+    .await
+    .and_then(|()| update_templates_cache(template_path, path));
+  events.emit(GeneratorEvent::DownloadFinished);
+
+  if downloaded.is_err() {
+    remove_template_dir(template_path, &install_path)?;
+    if cancel.is_cancelled() {
+      events.emit(GeneratorEvent::CleanedUp { path: install_path });
     }
-    std::process::exit(1);
-  })?;
-
-  download_dir(
-    &client,
-    &api_url,
-    &template_path.join(path),
-    true,
-    template_path,
-  )
-  .await?;
-
-  update_templates_cache(template_path, path)?;
-  println!("Template successfully downloaded");
-
-  Ok(())
-}
+  }
 
-#[derive(Serialize, Deserialize)]
-pub struct RegistryTemplate {
-  pub name: String,
-  pub path: String,
+  downloaded
 }
 
-pub async fn install_template_by_name(template_path: &Path, template_name: String) -> Result<()> {
-  let oxide_registry_file = template_path.join("oxide-registry.json");
-
+pub async fn install_template_by_name(
+  oxide_paths: &OxidePaths,
+  template_name: String,
+  config: &OxideConfig,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<()> {
+  let template_path = &oxide_paths.templates;
   let is_indstalled = is_template_installed(&template_name, template_path)?;
   if !is_indstalled {
-    let registry: Vec<RegistryTemplate> = if !oxide_registry_file.exists() {
-      let client = Client::builder().timeout(Duration::from_secs(30)).build()?;
-      let raw_url =
-        "https://raw.githubusercontent.com/oxide-cli/templates/main/oxide-registry.json";
-      let content = client
-        .get(raw_url)
-        .header(USER_AGENT, "oxide")
-        .send()
-        .await?
-        .text()
-        .await?;
-      let registry: Vec<RegistryTemplate> = serde_json::from_str(&content)?;
-      fs::write(&oxide_registry_file, &content)?;
-      registry
-    } else {
-      let content = fs::read_to_string(&oxide_registry_file)?;
-      serde_json::from_str(&content)?
-    };
+    let registry = get_registry(&oxide_paths.registry, config).await?;
 
     let entry = registry.iter().find(|t| t.name == template_name);
 
     if let Some(p) = entry {
-      install_template(template_path, &PathBuf::from(&p.path)).await?;
+      install_template(
+        template_path,
+        &PathBuf::from(&p.path),
+        config,
+        cancel,
+        events,
+      )
+      .await?;
     } else {
-      anyhow::bail!("Template '{}' not found in registry", template_name);
+      return Err(OxideError::TemplateNotFound(template_name).into());
     }
 
     Ok(())
   } else {
-    println!("This template is installed in");
+    info!("✓ Template '{}' is already installed", template_name);
     Ok(())
   }
 }
@@ -1,18 +1,50 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use anyhow::Result;
+use log::debug;
 
 use crate::{
-  templates::{TemplateFile, install::install_template},
-  utils::fs::read_dir_to_files,
+  cache::touch_template,
+  config::OxideConfig,
+  paths::OxidePaths,
+  project::events::EventSink,
+  templates::{
+    TemplateFile,
+    compose::{TemplateTree, layer_files, resolve_template_tree},
+  },
+  utils::cancel::CancelToken,
 };
 
-pub async fn get_files(path: PathBuf, template_path: &Path) -> Result<Vec<TemplateFile>> {
-  if !template_path.join(&path).exists() {
-    install_template(template_path, &path).await?;
-  }
+pub struct LoadedTemplate {
+  pub tree: TemplateTree,
+  pub files: Vec<TemplateFile>,
+}
+
+pub async fn get_files(
+  path: &Path,
+  oxide_paths: &OxidePaths,
+  config: &OxideConfig,
+  cancel: &CancelToken,
+  events: &EventSink,
+) -> Result<LoadedTemplate> {
+  let tree = resolve_template_tree(oxide_paths, path, config, cancel, events).await?;
+  let template_path = &oxide_paths.templates;
 
-  let files = read_dir_to_files(&template_path.join(&path))?;
+  let layers = tree.layers();
+  if layers.len() > 1 {
+    debug!(
+      "Layering {}",
+      layers
+        .iter()
+        .map(|l| l.name.as_str())
+        .collect::<Vec<_>>()
+        .join(" → ")
+    );
+  }
+  for layer in &layers {
+    touch_template(template_path, Path::new(&layer.path))?;
+  }
 
-  Ok(files)
+  let files = layer_files(template_path, &tree)?;
+  Ok(LoadedTemplate { tree, files })
 }
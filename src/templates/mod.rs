@@ -2,9 +2,15 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+pub mod catalogue;
+pub mod compose;
 pub mod generator;
+pub mod info;
 pub mod install;
 pub mod loader;
+pub mod outdated;
+pub mod registry;
+pub mod search;
 
 pub struct TemplateFile {
   pub path: PathBuf,
@@ -20,6 +26,12 @@ pub struct OxideTemplate {
   pub official: bool,
   pub repository: OxideTemplateRepository,
   pub metadata: OxideTemplateMetadata,
+  #[serde(default)]
+  pub variables: Vec<OxideTemplateVariable>,
+  #[serde(default)]
+  pub hooks: Vec<OxideTemplateHook>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub extends: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -33,3 +45,18 @@ pub struct OxideTemplateMetadata {
   pub display_name: String,
   pub description: String,
 }
+
+#[derive(Serialize, Deserialize)]
+pub struct OxideTemplateVariable {
+  pub name: String,
+  #[serde(default)]
+  pub description: Option<String>,
+  #[serde(default)]
+  pub default: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OxideTemplateHook {
+  pub event: String,
+  pub command: String,
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use comfy_table::{Attribute, Cell, Color, Table};
+use semver::Version;
+use serde::Serialize;
+
+use crate::{
+  cache::load_templates_cache, config::OxideConfig, output::Render, paths::OxidePaths,
+  templates::registry::get_registry,
+};
+
+#[derive(Serialize)]
+pub struct OutdatedTemplate {
+  pub name: String,
+  pub current: String,
+  pub latest: String,
+}
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct OutdatedTemplates(pub Vec<OutdatedTemplate>);
+
+pub async fn get_outdated_templates(
+  oxide_paths: &OxidePaths,
+  config: &OxideConfig,
+) -> Result<OutdatedTemplates> {
+  let templates_info = load_templates_cache(&oxide_paths.templates)?;
+
+  if templates_info.templates.is_empty() {
+    return Ok(OutdatedTemplates(Vec::new()));
+  }
+
+  let registry = get_registry(&oxide_paths.registry, config).await?;
+
+  let outdated = templates_info
+    .templates
+    .into_iter()
+    .filter_map(|cached| {
+      let latest = registry
+        .iter()
+        .find(|t| t.name == cached.name)?
+        .version
+        .clone()?;
+
+      if !is_newer(&latest, &cached.version) {
+        return None;
+      }
+
+      Some(OutdatedTemplate {
+        name: cached.name,
+        current: cached.version,
+        latest,
+      })
+    })
+    .collect();
+
+  Ok(OutdatedTemplates(outdated))
+}
+
+pub fn is_newer(latest: &str, current: &str) -> bool {
+  match (Version::parse(latest), Version::parse(current)) {
+    (Ok(latest), Ok(current)) => latest > current,
+    _ => latest != current,
+  }
+}
+
+impl Render for OutdatedTemplates {
+  fn to_table(&self) -> String {
+    if self.0.is_empty() {
+      return "All templates are up to date.".to_string();
+    }
+
+    let mut table = Table::new();
+
+    table.set_header(vec![
+      Cell::new("Name").add_attribute(Attribute::Bold),
+      Cell::new("Current").add_attribute(Attribute::Bold),
+      Cell::new("Latest").add_attribute(Attribute::Bold),
+    ]);
+
+    for template in &self.0 {
+      table.add_row(vec![
+        Cell::new(&template.name),
+        Cell::new(&template.current).fg(Color::Red),
+        Cell::new(&template.latest).fg(Color::Green),
+      ]);
+    }
+
+    table.to_string()
+  }
+
+  fn to_plain(&self) -> String {
+    self
+      .0
+      .iter()
+      .map(|t| format!("{}\t{}\t{}", t.name, t.current, t.latest))
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
@@ -0,0 +1,253 @@
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use log::{debug, warn};
+use reqwest::{
+  StatusCode,
+  header::{ETAG, IF_NONE_MATCH, USER_AGENT},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  config::OxideConfig,
+  prompts::Language,
+  templates::catalogue::Catalogue,
+  utils::{
+    fs::write_atomic,
+    http::{build_client, send},
+  },
+};
+
+const REGISTRY_URL: &str =
+  "https://raw.githubusercontent.com/oxide-cli/templates/main/oxide-registry.json";
+
+#[derive(Serialize, Deserialize)]
+pub struct RegistryTemplate {
+  pub name: String,
+  pub path: String,
+  #[serde(default)]
+  pub version: Option<String>,
+  #[serde(rename = "displayName", default)]
+  pub display_name: Option<String>,
+  #[serde(default)]
+  pub description: Option<String>,
+  #[serde(default)]
+  pub tags: Vec<String>,
+  #[serde(default)]
+  pub layer: Option<String>,
+  #[serde(default)]
+  pub framework: Option<String>,
+  #[serde(default, skip_serializing_if = "TemplateKind::is_project")]
+  pub kind: TemplateKind,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateKind {
+  #[default]
+  Project,
+  Feature,
+}
+
+impl TemplateKind {
+  fn is_project(&self) -> bool {
+    *self == TemplateKind::Project
+  }
+}
+
+impl RegistryTemplate {
+  pub fn language(&self) -> Option<Language> {
+    match self.path.split('/').next() {
+      Some("ts") => Some(Language::TypeScript),
+      Some("js") => Some(Language::JavaScript),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RegistryMeta {
+  #[serde(rename = "fetchedAt")]
+  pub fetched_at: String,
+  pub etag: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RegistryDocument {
+  Full {
+    templates: Vec<RegistryTemplate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    catalogue: Option<Catalogue>,
+  },
+  Listing(Vec<RegistryTemplate>),
+}
+
+impl RegistryDocument {
+  pub fn templates(&self) -> &[RegistryTemplate] {
+    match self {
+      RegistryDocument::Full { templates, .. } | RegistryDocument::Listing(templates) => templates,
+    }
+  }
+
+  pub fn templates_mut(&mut self) -> &mut Vec<RegistryTemplate> {
+    match self {
+      RegistryDocument::Full { templates, .. } | RegistryDocument::Listing(templates) => templates,
+    }
+  }
+
+  pub fn into_templates(self) -> Vec<RegistryTemplate> {
+    match self {
+      RegistryDocument::Full { templates, .. } | RegistryDocument::Listing(templates) => templates,
+    }
+  }
+
+  pub fn into_catalogue(self) -> Option<Catalogue> {
+    match self {
+      RegistryDocument::Full { catalogue, .. } => catalogue,
+      RegistryDocument::Listing(_) => None,
+    }
+  }
+}
+
+pub enum RegistryFetch {
+  Updated(RegistryDocument),
+  NotModified(RegistryDocument),
+}
+
+pub async fn get_registry(
+  registry_file: &Path,
+  config: &OxideConfig,
+) -> Result<Vec<RegistryTemplate>> {
+  Ok(load_registry(registry_file, config).await?.into_templates())
+}
+
+pub async fn get_catalogue(registry_file: &Path, config: &OxideConfig) -> Catalogue {
+  match load_registry(registry_file, config).await {
+    Ok(document) => document.into_catalogue().unwrap_or_else(|| {
+      debug!("Registry has no framework catalogue, using the embedded copy");
+      Catalogue::embedded()
+    }),
+    Err(e) => {
+      debug!(
+        "Could not load registry ({}), using the embedded catalogue",
+        e
+      );
+      Catalogue::embedded()
+    }
+  }
+}
+
+async fn load_registry(registry_file: &Path, config: &OxideConfig) -> Result<RegistryDocument> {
+  let meta = read_registry_meta(registry_file)?;
+
+  let is_fresh = meta
+    .as_ref()
+    .is_some_and(|m| !is_stale(m, config.registry_ttl_hours));
+
+  if registry_file.exists() && (is_fresh || config.offline) {
+    debug!(
+      "Using cached registry {} ({})",
+      registry_file.display(),
+      if is_fresh { "fresh" } else { "offline" }
+    );
+    return read_registry_file(registry_file);
+  }
+
+  config.ensure_online(&["template registry".to_string()])?;
+  debug!("Registry is missing or stale, fetching a new copy");
+
+  match fetch_registry(registry_file, meta, config).await {
+    Ok(RegistryFetch::Updated(document) | RegistryFetch::NotModified(document)) => Ok(document),
+    Err(e) if registry_file.exists() => {
+      warn!("⚠ Could not refresh registry ({}), using cached copy", e);
+      read_registry_file(registry_file)
+    }
+    Err(e) => Err(e),
+  }
+}
+
+pub async fn refresh_registry(registry_file: &Path, config: &OxideConfig) -> Result<RegistryFetch> {
+  config.ensure_online(&["template registry".to_string()])?;
+  let meta = read_registry_meta(registry_file)?;
+  fetch_registry(registry_file, meta, config).await
+}
+
+async fn fetch_registry(
+  registry_file: &Path,
+  meta: Option<RegistryMeta>,
+  config: &OxideConfig,
+) -> Result<RegistryFetch> {
+  let client = build_client(config)?;
+
+  let previous_etag = meta.and_then(|m| m.etag);
+
+  let mut request = client.get(REGISTRY_URL).header(USER_AGENT, "oxide");
+  if registry_file.exists()
+    && let Some(etag) = &previous_etag
+  {
+    request = request.header(IF_NONE_MATCH, etag);
+  }
+
+  let response = send(request).await?.error_for_status()?;
+  let etag = response
+    .headers()
+    .get(ETAG)
+    .and_then(|v| v.to_str().ok())
+    .map(String::from);
+
+  if response.status() == StatusCode::NOT_MODIFIED {
+    debug!("Registry not modified since last fetch");
+    write_registry_meta(registry_file, etag.or(previous_etag))?;
+    return Ok(RegistryFetch::NotModified(read_registry_file(
+      registry_file,
+    )?));
+  }
+
+  let content = response.text().await?;
+  let document: RegistryDocument = serde_json::from_str(&content)?;
+
+  write_atomic(registry_file, &content)?;
+  write_registry_meta(registry_file, etag)?;
+
+  Ok(RegistryFetch::Updated(document))
+}
+
+fn is_stale(meta: &RegistryMeta, ttl_hours: u64) -> bool {
+  match DateTime::parse_from_rfc3339(&meta.fetched_at) {
+    Ok(fetched_at) => {
+      let age = Utc::now().signed_duration_since(fetched_at);
+      age.num_hours() >= ttl_hours as i64
+    }
+    Err(_) => true,
+  }
+}
+
+pub fn read_registry_file(registry_file: &Path) -> Result<RegistryDocument> {
+  let content = fs::read_to_string(registry_file)?;
+  Ok(serde_json::from_str(&content)?)
+}
+
+fn read_registry_meta(registry_file: &Path) -> Result<Option<RegistryMeta>> {
+  let meta_file = registry_file.with_extension("meta.json");
+
+  if !meta_file.exists() {
+    return Ok(None);
+  }
+
+  let content = fs::read_to_string(&meta_file)?;
+  Ok(serde_json::from_str(&content).ok())
+}
+
+fn write_registry_meta(registry_file: &Path, etag: Option<String>) -> Result<()> {
+  let meta = RegistryMeta {
+    fetched_at: Utc::now().to_rfc3339(),
+    etag,
+  };
+
+  write_atomic(
+    &registry_file.with_extension("meta.json"),
+    serde_json::to_string_pretty(&meta)?,
+  )
+}
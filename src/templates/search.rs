@@ -0,0 +1,183 @@
+use comfy_table::{Attribute, Cell, Table};
+use serde::Serialize;
+
+use crate::{output::Render, prompts::Language, templates::registry::RegistryTemplate};
+
+pub struct RegistryFilter {
+  pub layer: Option<String>,
+  pub framework: Option<String>,
+  pub language: Option<Language>,
+}
+
+impl RegistryFilter {
+  pub fn matches(&self, template: &RegistryTemplate) -> bool {
+    let layer_matches = self.layer.as_ref().is_none_or(|layer| {
+      template
+        .layer
+        .as_ref()
+        .is_some_and(|l| l.eq_ignore_ascii_case(layer))
+    });
+
+    let framework_matches = self.framework.as_ref().is_none_or(|framework| {
+      template
+        .framework
+        .as_ref()
+        .is_some_and(|f| f.eq_ignore_ascii_case(framework))
+    });
+
+    let language_matches = self
+      .language
+      .is_none_or(|language| template.language() == Some(language));
+
+    layer_matches && framework_matches && language_matches
+  }
+}
+
+pub fn filter_registry<'a>(
+  registry: &'a [RegistryTemplate],
+  filter: &RegistryFilter,
+) -> Vec<&'a RegistryTemplate> {
+  registry.iter().filter(|t| filter.matches(t)).collect()
+}
+
+pub fn search_registry<'a>(
+  registry: &'a [RegistryTemplate],
+  query: &str,
+  filter: &RegistryFilter,
+) -> Vec<&'a RegistryTemplate> {
+  let mut results: Vec<(i64, &RegistryTemplate)> = registry
+    .iter()
+    .filter(|t| filter.matches(t))
+    .filter_map(|t| template_score(t, query).map(|score| (score, t)))
+    .collect();
+
+  results.sort_by(|(a_score, a), (b_score, b)| b_score.cmp(a_score).then(a.name.cmp(&b.name)));
+
+  results.into_iter().map(|(_, t)| t).collect()
+}
+
+fn template_score(template: &RegistryTemplate, query: &str) -> Option<i64> {
+  let name_score = fuzzy_score(query, &template.name).map(|s| s * 3);
+  let display_name_score = template
+    .display_name
+    .as_deref()
+    .and_then(|d| fuzzy_score(query, d))
+    .map(|s| s * 2);
+  let description_score = template
+    .description
+    .as_deref()
+    .and_then(|d| fuzzy_score(query, d));
+  let tags_score = template
+    .tags
+    .iter()
+    .filter_map(|tag| fuzzy_score(query, tag).map(|s| s * 2))
+    .max();
+
+  [
+    name_score,
+    display_name_score,
+    description_score,
+    tags_score,
+  ]
+  .into_iter()
+  .flatten()
+  .max()
+}
+
+fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+  let query = query.to_lowercase();
+  let text = text.to_lowercase();
+
+  if query.is_empty() {
+    return Some(0);
+  }
+
+  if text == query {
+    return Some(1000);
+  }
+
+  if let Some(index) = text.find(&query) {
+    return Some(500 - index as i64);
+  }
+
+  let mut score = 0;
+  let mut streak = 0;
+  let mut query_chars = query.chars().peekable();
+
+  for c in text.chars() {
+    match query_chars.peek() {
+      Some(&q) if q == c => {
+        streak += 1;
+        score += 10 * streak;
+        query_chars.next();
+      }
+      Some(_) => {
+        streak = 0;
+        score -= 1;
+      }
+      None => break,
+    }
+  }
+
+  if query_chars.peek().is_some() {
+    return None;
+  }
+
+  Some(score.max(1))
+}
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct RegistryListing<'a>(pub Vec<&'a RegistryTemplate>);
+
+impl Render for RegistryListing<'_> {
+  fn to_table(&self) -> String {
+    if self.0.is_empty() {
+      return "No templates found.".to_string();
+    }
+
+    let mut table = Table::new();
+
+    table.set_header(vec![
+      Cell::new("Name").add_attribute(Attribute::Bold),
+      Cell::new("Description").add_attribute(Attribute::Bold),
+      Cell::new("Layer").add_attribute(Attribute::Bold),
+      Cell::new("Framework").add_attribute(Attribute::Bold),
+      Cell::new("Language").add_attribute(Attribute::Bold),
+      Cell::new("Tags").add_attribute(Attribute::Bold),
+    ]);
+
+    for template in &self.0 {
+      table.add_row(vec![
+        Cell::new(&template.name),
+        Cell::new(
+          template
+            .description
+            .as_deref()
+            .or(template.display_name.as_deref())
+            .unwrap_or("-"),
+        ),
+        Cell::new(template.layer.as_deref().unwrap_or("-")),
+        Cell::new(template.framework.as_deref().unwrap_or("-")),
+        Cell::new(
+          template
+            .language()
+            .map(|l| l.to_string())
+            .unwrap_or("-".to_string()),
+        ),
+        Cell::new(template.tags.join(", ")),
+      ]);
+    }
+
+    table.to_string()
+  }
+
+  fn to_plain(&self) -> String {
+    self
+      .0
+      .iter()
+      .map(|t| format!("{}\t{}", t.name, t.description.as_deref().unwrap_or("")))
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
@@ -0,0 +1,43 @@
+use std::process::ExitCode;
+
+use colored::Colorize;
+use inquire::InquireError;
+use oxide_cli::error::OxideError;
+
+use crate::terminal::logging::FILE_ONLY;
+
+pub fn report_error(error: &anyhow::Error) -> ExitCode {
+  log::error!(target: FILE_ONLY, "{:#}", error);
+
+  let cancelled = error.chain().any(|cause| {
+    matches!(
+      cause.downcast_ref::<InquireError>(),
+      Some(InquireError::OperationCanceled | InquireError::OperationInterrupted)
+    ) || matches!(
+      cause.downcast_ref::<OxideError>(),
+      Some(OxideError::Cancelled)
+    )
+  });
+
+  if cancelled {
+    eprintln!("✗ {}", OxideError::Cancelled);
+    return ExitCode::from(OxideError::Cancelled.exit_code());
+  }
+
+  let oxide_error = error.chain().find_map(|c| c.downcast_ref::<OxideError>());
+
+  eprintln!("{} {}", "error:".red().bold(), error);
+  for cause in error.chain().skip(1) {
+    eprintln!("  caused by: {}", cause);
+  }
+
+  match oxide_error {
+    Some(e) => {
+      if let Some(hint) = e.hint() {
+        eprintln!("{} {}", "hint:".cyan().bold(), hint);
+      }
+      ExitCode::from(e.exit_code())
+    }
+    None => ExitCode::FAILURE,
+  }
+}
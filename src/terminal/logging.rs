@@ -0,0 +1,109 @@
+use std::{
+  fs::{File, OpenOptions},
+  io::Write,
+  path::Path,
+  sync::{
+    Mutex,
+    atomic::{AtomicBool, Ordering},
+  },
+};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use colored::Colorize;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde_json::json;
+
+use crate::terminal::progress;
+
+pub const FILE_ONLY: &str = "oxide::file";
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+struct OxideLogger {
+  console: LevelFilter,
+  file: Option<Mutex<File>>,
+}
+
+pub fn init(verbose: u8, quiet: bool, log_file: Option<&Path>) -> Result<()> {
+  let console = match (quiet, verbose) {
+    (true, _) => LevelFilter::Error,
+    (false, 0) => LevelFilter::Info,
+    (false, 1) => LevelFilter::Debug,
+    (false, _) => LevelFilter::Trace,
+  };
+
+  let file = log_file
+    .map(|path| {
+      OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Could not open log file {}", path.display()))
+    })
+    .transpose()?;
+
+  let max_level = if file.is_some() {
+    console.max(LevelFilter::Debug)
+  } else {
+    console
+  };
+
+  log::set_boxed_logger(Box::new(OxideLogger {
+    console,
+    file: file.map(Mutex::new),
+  }))?;
+  log::set_max_level(max_level);
+  QUIET.store(quiet, Ordering::SeqCst);
+
+  Ok(())
+}
+
+pub fn is_quiet() -> bool {
+  QUIET.load(Ordering::SeqCst)
+}
+
+impl Log for OxideLogger {
+  fn enabled(&self, metadata: &Metadata) -> bool {
+    let ours = metadata.target().starts_with("oxide") || metadata.level() <= Level::Warn;
+    ours && (metadata.level() <= self.console || self.file.is_some())
+  }
+
+  fn log(&self, record: &Record) {
+    if !self.enabled(record.metadata()) {
+      return;
+    }
+
+    if record.level() <= self.console && record.target() != FILE_ONLY {
+      let line = match record.level() {
+        Level::Error => format!("{} {}", "error:".red().bold(), record.args()),
+        Level::Warn | Level::Info => record.args().to_string(),
+        level => format!(
+          "{}",
+          format!("[{}] {}", level.as_str().to_lowercase(), record.args()).dimmed()
+        ),
+      };
+      progress::suspend(|| eprintln!("{}", line));
+    }
+
+    if let Some(file) = &self.file
+      && let Ok(mut file) = file.lock()
+    {
+      let entry = json!({
+        "timestamp": Utc::now().to_rfc3339(),
+        "level": record.level().as_str(),
+        "target": record.target(),
+        "message": record.args().to_string(),
+      });
+      let _ = writeln!(file, "{}", entry);
+    }
+  }
+
+  fn flush(&self) {
+    if let Some(file) = &self.file
+      && let Ok(mut file) = file.lock()
+    {
+      let _ = file.flush();
+    }
+  }
+}
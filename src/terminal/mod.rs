@@ -0,0 +1,6 @@
+pub mod error;
+pub mod logging;
+pub mod output;
+pub mod progress;
+pub mod prompts;
+pub mod setup;
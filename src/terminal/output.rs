@@ -0,0 +1,16 @@
+use anyhow::Result;
+use oxide_cli::output::{OutputFormat, Render};
+
+pub fn render<T: Render>(value: &T, format: OutputFormat) -> Result<()> {
+  match format {
+    OutputFormat::Table => println!("{}", value.to_table()),
+    OutputFormat::Json => println!("{}", serde_json::to_string_pretty(value)?),
+    OutputFormat::Plain => {
+      let plain = value.to_plain();
+      if !plain.is_empty() {
+        println!("{}", plain);
+      }
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,207 @@
+use std::{
+  io::{IsTerminal, stderr},
+  sync::{Mutex, OnceLock},
+  time::Duration,
+};
+
+use colored::Colorize;
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use log::{debug, info, trace};
+
+use oxide_cli::project::events::{EventSink, GeneratorEvent};
+
+use crate::terminal::logging::is_quiet;
+
+pub fn is_interactive() -> bool {
+  stderr().is_terminal() && !is_quiet()
+}
+
+fn multi() -> &'static MultiProgress {
+  static MULTI: OnceLock<MultiProgress> = OnceLock::new();
+
+  MULTI.get_or_init(|| {
+    if is_interactive() {
+      MultiProgress::with_draw_target(ProgressDrawTarget::stderr())
+    } else {
+      MultiProgress::with_draw_target(ProgressDrawTarget::hidden())
+    }
+  })
+}
+
+pub fn suspend<F: FnOnce() -> R, R>(f: F) -> R {
+  multi().suspend(f)
+}
+
+fn style(template: &str) -> ProgressStyle {
+  ProgressStyle::with_template(template)
+    .unwrap()
+    .progress_chars("=> ")
+}
+
+#[derive(Default)]
+struct Reporter {
+  download: Option<ProgressBar>,
+  files: u64,
+  total_files: u64,
+  render: Option<ProgressBar>,
+  install: Option<ProgressBar>,
+  output: Vec<String>,
+}
+
+impl Reporter {
+  fn handle(&mut self, event: &GeneratorEvent) {
+    match event {
+      GeneratorEvent::DownloadStarted { path } => {
+        let bar = multi().add(ProgressBar::new(0));
+        bar.set_style(style(
+          "{prefix} [{bar:30}] {bytes}/{total_bytes} ({bytes_per_sec}) {msg}",
+        ));
+        bar.set_prefix(format!("Downloading {}", path.display()));
+        self.download = Some(bar);
+        self.files = 0;
+        self.total_files = 0;
+      }
+      GeneratorEvent::DownloadQueued { files, bytes } => {
+        self.total_files += files;
+        if let Some(bar) = &self.download {
+          bar.inc_length(*bytes);
+        }
+        self.update_download();
+      }
+      GeneratorEvent::FileDownloaded { path, bytes } => {
+        self.files += 1;
+        if let Some(bar) = &self.download {
+          bar.inc(*bytes);
+        }
+        self.update_download();
+
+        if is_interactive() {
+          debug!("Downloaded {}", path.display());
+        } else {
+          info!("✓ {}", path.display());
+        }
+      }
+      GeneratorEvent::DownloadFinished => {
+        if let Some(bar) = self.download.take() {
+          bar.finish_and_clear();
+        }
+      }
+      GeneratorEvent::RenderStarted { files } => {
+        let bar = multi().add(ProgressBar::new(*files as u64));
+        bar.set_style(style("Rendering [{bar:30}] {pos}/{len} {wide_msg}"));
+        self.render = Some(bar);
+      }
+      GeneratorEvent::FileRendered { path } => {
+        if let Some(bar) = &self.render {
+          bar.set_message(path.display().to_string());
+          bar.inc(1);
+        }
+      }
+      GeneratorEvent::RenderFinished => {
+        if let Some(bar) = self.render.take() {
+          bar.finish_and_clear();
+        }
+      }
+      GeneratorEvent::FileWritten { path } => {
+        if is_interactive() {
+          debug!("Wrote {}", path.display());
+        } else {
+          info!("  ✓ {}", path.display());
+        }
+      }
+      GeneratorEvent::FileOverwritten { path } => info!("  ✎ Overwrote {}", path.display()),
+      GeneratorEvent::FilePatched { path } => debug!("Patched {}", path.display()),
+      GeneratorEvent::FileSkipped { path } => info!("  - Kept existing {}", path.display()),
+      GeneratorEvent::ConflictDiff { diff, .. } => suspend(|| print_diff(diff)),
+      GeneratorEvent::InstallStarted { package_manager } => {
+        self.install = Some(spinner(format!(
+          "Installing dependencies with {}",
+          package_manager
+        )));
+        self.output.clear();
+      }
+      GeneratorEvent::InstallOutput { line } => {
+        if let Some(spinner) = &self.install
+          && !line.trim().is_empty()
+        {
+          spinner.set_message(line.clone());
+        }
+        if is_interactive() || is_quiet() {
+          trace!("{}", line);
+        } else {
+          info!("{}", line);
+        }
+        self.output.push(line.clone());
+      }
+      GeneratorEvent::InstallFinished { success } => {
+        if let Some(spinner) = self.install.take() {
+          spinner.finish_and_clear();
+        }
+        if !success && (is_interactive() || is_quiet()) {
+          for line in self.output.drain(..) {
+            suspend(|| eprintln!("{}", line));
+          }
+        }
+      }
+      GeneratorEvent::CleanedUp { path } => {
+        for bar in [
+          self.download.take(),
+          self.render.take(),
+          self.install.take(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+          bar.finish_and_clear();
+        }
+        info!("✓ Removed incomplete {}", path.display());
+      }
+      GeneratorEvent::MemberStarted { path } => info!("→ {}", path.display()),
+      GeneratorEvent::TemplateResolved { .. } | GeneratorEvent::TemplateCached { .. } => {}
+    }
+  }
+
+  fn update_download(&self) {
+    if let Some(bar) = &self.download {
+      bar.set_message(format!("{}/{} files", self.files, self.total_files));
+    }
+  }
+}
+
+pub fn print_diff(diff: &str) {
+  for line in diff.lines() {
+    if line.starts_with("+++") || line.starts_with("---") {
+      println!("{}", line.bold());
+    } else if line.starts_with('+') {
+      println!("{}", line.green());
+    } else if line.starts_with('-') {
+      println!("{}", line.red());
+    } else if line.starts_with("@@") {
+      println!("{}", line.cyan());
+    } else {
+      println!("{}", line);
+    }
+  }
+}
+
+pub fn cli_events() -> EventSink {
+  let reporter = Mutex::new(Reporter::default());
+
+  EventSink::new(move |event| {
+    if let Ok(mut reporter) = reporter.lock() {
+      reporter.handle(event);
+    }
+  })
+}
+
+fn spinner(message: impl Into<String>) -> ProgressBar {
+  let spinner = multi().add(ProgressBar::new_spinner());
+  spinner.set_style(
+    ProgressStyle::with_template("{spinner} {prefix} {wide_msg}")
+      .unwrap()
+      .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏ "),
+  );
+  spinner.set_prefix(message.into());
+  spinner.enable_steady_tick(Duration::from_millis(100));
+  spinner
+}
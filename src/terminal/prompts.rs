@@ -0,0 +1,169 @@
+use std::{fmt, path::PathBuf};
+
+use oxide_cli::{
+  project::{
+    conflict::{ConflictResolution, FileConflict},
+    workspace::check_member_path,
+  },
+  prompts::{Language, PackageManager},
+  templates::catalogue::{Catalogue, CatalogueFramework, CatalogueLayer, CatalogueVariant},
+};
+
+use crate::terminal::progress::print_diff;
+use anyhow::{Result, anyhow};
+use inquire::{Confirm, Select, Text};
+use regex::Regex;
+
+pub fn ask_project_name() -> Result<String> {
+  Ok(
+    Text::new("Project name:")
+      .with_placeholder("my-awesome-app")
+      .with_help_message("Enter your project name")
+      .prompt()?,
+  )
+}
+
+pub fn ask_project_layer(catalogue: &Catalogue) -> Result<&CatalogueLayer> {
+  let layers = catalogue.layers.iter().collect();
+
+  let layer = Select::new("Select a layer:", layers).prompt()?;
+  Ok(layer)
+}
+
+pub enum LayerChoice<'a> {
+  Layer(&'a CatalogueLayer),
+  Fullstack,
+}
+
+impl fmt::Display for LayerChoice<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LayerChoice::Layer(layer) => write!(f, "{}", layer),
+      LayerChoice::Fullstack => write!(f, "Fullstack (frontend + backend)"),
+    }
+  }
+}
+
+pub fn ask_layer_or_fullstack(catalogue: &Catalogue) -> Result<LayerChoice<'_>> {
+  let mut choices: Vec<LayerChoice> = catalogue.layers.iter().map(LayerChoice::Layer).collect();
+  choices.push(LayerChoice::Fullstack);
+
+  let choice = Select::new("Select a layer:", choices).prompt()?;
+  Ok(choice)
+}
+
+pub fn ask_framework(layer: &CatalogueLayer) -> Result<&CatalogueFramework> {
+  let frameworks = layer.frameworks.iter().collect();
+
+  let framework = Select::new("Select a framework:", frameworks).prompt()?;
+  Ok(framework)
+}
+
+pub fn ask_build_tool(framework: &CatalogueFramework) -> Result<&str> {
+  let build_tools = framework.build_tools.iter().map(|b| b.as_str()).collect();
+
+  let build_tool = Select::new("Select a build tool:", build_tools).prompt()?;
+  Ok(build_tool)
+}
+
+pub fn ask_language(framework: &CatalogueFramework) -> Result<Language> {
+  let language = Select::new("Select a language:", framework.languages.clone()).prompt()?;
+  Ok(language)
+}
+
+pub fn ask_platform<'a>(
+  framework: &'a CatalogueFramework,
+  build_tool: Option<&str>,
+) -> Result<&'a CatalogueVariant> {
+  let platforms = framework.variants_for(build_tool);
+
+  if platforms.is_empty() {
+    return Err(anyhow!(
+      "No available platforms for the selected framework/build tool"
+    ));
+  }
+
+  let platform = Select::new("Select a platform:", platforms).prompt()?;
+  Ok(platform)
+}
+
+pub fn ask_user_name() -> Result<String> {
+  let valid_chars = Regex::new(r"^[a-zA-Z0-9_\-\.]+$").unwrap();
+
+  let user_name = Text::new("Your name:")
+    .with_placeholder("maksym")
+    .with_help_message("Enter your name")
+    .prompt()?
+    .to_lowercase();
+
+  if !valid_chars.is_match(&user_name) {
+    return Err(anyhow!(
+      "Name can only contain letters, numbers, hyphens, underscores, and dots"
+    ));
+  }
+
+  Ok(user_name)
+}
+
+pub fn ask_package_manager() -> Result<PackageManager> {
+  let package_managers = vec![
+    PackageManager::NPM,
+    PackageManager::Yarn,
+    PackageManager::PNPM,
+    PackageManager::Bun,
+  ];
+
+  let package_manager = Select::new("Select a package manager:", package_managers).prompt()?;
+  Ok(package_manager)
+}
+
+pub fn ask_conflict_resolution(conflict: &FileConflict) -> Result<(ConflictResolution, bool)> {
+  const OVERWRITE: &str = "Overwrite";
+  const KEEP: &str = "Keep existing";
+  const DIFF: &str = "Show diff";
+  const OVERWRITE_ALL: &str = "Overwrite all remaining";
+  const KEEP_ALL: &str = "Keep all remaining";
+
+  let resolution = loop {
+    let choice = Select::new(
+      &format!("{} already exists:", conflict.path.display()),
+      vec![OVERWRITE, KEEP, DIFF, OVERWRITE_ALL, KEEP_ALL],
+    )
+    .prompt()?;
+
+    match choice {
+      OVERWRITE => break (ConflictResolution::Overwrite, false),
+      OVERWRITE_ALL => break (ConflictResolution::Overwrite, true),
+      KEEP_ALL => break (ConflictResolution::Skip, true),
+      DIFF => {
+        print_diff(&conflict.diff());
+        continue;
+      }
+      _ => break (ConflictResolution::Skip, false),
+    }
+  };
+
+  Ok(resolution)
+}
+
+pub fn ask_add_member(first: bool) -> Result<bool> {
+  let message = if first {
+    "Add an app or package to the workspace?"
+  } else {
+    "Add another app or package?"
+  };
+
+  Ok(Confirm::new(message).with_default(first).prompt()?)
+}
+
+pub fn ask_member_path(default: &str) -> Result<PathBuf> {
+  let path = PathBuf::from(
+    Text::new("Directory:")
+      .with_default(default)
+      .with_help_message("Relative to the workspace root, e.g. apps/web or packages/ui")
+      .prompt()?,
+  );
+
+  check_member_path(&path)?;
+  Ok(path)
+}
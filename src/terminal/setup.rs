@@ -0,0 +1,446 @@
+use std::{
+  fs,
+  io::{self, Write},
+  path::PathBuf,
+  sync::Mutex,
+};
+
+use anyhow::{Result, anyhow, bail};
+use log::{debug, info, warn};
+
+use oxide_cli::{
+  config::OxideConfig,
+  output::OutputFormat,
+  paths::OxidePaths,
+  project::{
+    InstallPolicy, ProjectGenerator, TemplateSource,
+    conflict::{ConflictResolution, FileConflict, FileConflictPolicy},
+    feature::FeatureApplier,
+    fullstack::{API_PORT, TYPES_PATH},
+    member::MemberAdder,
+    metadata::ProjectMetadata,
+    preview::{DryRunReport, show_file},
+    workspace::{MemberSpec, WorkspaceGenerator, WorkspaceMember, WorkspaceTool},
+  },
+  prompts::{Language, PackageManager},
+  templates::{
+    catalogue::{Catalogue, CatalogueFramework, CatalogueLayer},
+    install::install_template,
+    registry::get_catalogue,
+  },
+  utils::{cancel::CancelToken, fs::relative_key},
+};
+
+use crate::terminal::{
+  logging::is_quiet,
+  output::render,
+  progress::{cli_events, is_interactive, suspend},
+  prompts::{
+    LayerChoice, ask_add_member, ask_build_tool, ask_conflict_resolution, ask_framework,
+    ask_language, ask_layer_or_fullstack, ask_member_path, ask_package_manager, ask_platform,
+    ask_project_layer, ask_user_name,
+  },
+};
+
+pub struct SetupProjectOptions {
+  pub project_name: Option<String>,
+  pub layer: Option<String>,
+  pub framework: Option<String>,
+  pub build_tool: Option<String>,
+  pub language: Option<Language>,
+  pub platform: Option<String>,
+  pub package_manager: Option<PackageManager>,
+  pub conflict: Option<FileConflictPolicy>,
+  pub dry_run: bool,
+  pub show: Option<PathBuf>,
+}
+
+pub async fn setup_project(
+  setup_options: SetupProjectOptions,
+  home_dir: &OxidePaths,
+  config: &OxideConfig,
+  is_install: bool,
+  format: OutputFormat,
+  cancel: &CancelToken,
+) -> Result<()> {
+  let catalogue = get_catalogue(&home_dir.registry, config).await;
+
+  let layer = match (&setup_options.layer, &setup_options.framework) {
+    (Some(l), _) => Some(catalogue.find_layer(l)?),
+    (None, None) if !is_install => match ask_layer_or_fullstack(&catalogue)? {
+      LayerChoice::Layer(layer) => Some(layer),
+      LayerChoice::Fullstack => {
+        if setup_options.dry_run {
+          bail!("--dry-run is not supported for full-stack projects yet");
+        }
+        let name = setup_options
+          .project_name
+          .ok_or_else(|| anyhow!("Project name is required"))?;
+        return setup_workspace(
+          SetupWorkspaceOptions {
+            name,
+            tool: None,
+            apps: Vec::new(),
+            package_manager: setup_options.package_manager,
+            conflict: setup_options.conflict,
+            fullstack: true,
+            frontend: None,
+            backend: None,
+          },
+          home_dir,
+          config,
+          cancel,
+        )
+        .await;
+      }
+    },
+    _ => None,
+  };
+
+  let (framework, path) = choose_template(
+    &catalogue,
+    layer,
+    setup_options.framework.as_deref(),
+    setup_options.build_tool.as_deref(),
+    setup_options.language,
+    setup_options.platform.as_deref(),
+  )?;
+
+  if is_install {
+    return install_template(&home_dir.templates, &path, config, cancel, &cli_events()).await;
+  }
+
+  let project_name = setup_options
+    .project_name
+    .ok_or_else(|| anyhow!("Project name is required"))?;
+
+  let mut generator = ProjectGenerator::new(TemplateSource::Path(path), project_name.as_str())
+    .paths(home_dir.clone())
+    .config(config.clone())
+    .cancel_token(cancel.clone())
+    .conflict_policy(setup_options.conflict)
+    .on_conflict(prompt_conflicts())
+    .events(cli_events());
+
+  if framework.ask_user_name {
+    generator = generator.answer("tauri_user_name", ask_user_name()?);
+  }
+
+  if setup_options.dry_run {
+    let project = generator.dry_run(true).generate().await?;
+
+    match &setup_options.show {
+      Some(path) => io::stdout().write_all(show_file(&project, path)?)?,
+      None => render(&DryRunReport::new(&project), format)?,
+    }
+    return Ok(());
+  }
+
+  let package_manager = match setup_options.package_manager {
+    Some(pm) => pm,
+    None => ask_package_manager()?,
+  };
+
+  let project = generator
+    .install(InstallPolicy::Run(package_manager))
+    .generate()
+    .await?;
+
+  if is_quiet() {
+    println!("{}", fs::canonicalize(&project.path)?.display());
+  } else {
+    info!("✅ Project created successfully!");
+    info!("\nNext steps:");
+    if project_name != "." {
+      info!("  cd {}", project_name);
+    }
+    info!("  {} run dev", package_manager);
+  }
+
+  Ok(())
+}
+
+fn choose_template<'a>(
+  catalogue: &'a Catalogue,
+  layer: Option<&'a CatalogueLayer>,
+  framework: Option<&str>,
+  build_tool: Option<&str>,
+  language: Option<Language>,
+  platform: Option<&str>,
+) -> Result<(&'a CatalogueFramework, PathBuf)> {
+  let framework = match (layer, framework) {
+    (Some(layer), Some(f)) => layer.find_framework(f)?,
+    (None, Some(f)) => catalogue.find_framework(f)?.1,
+    (Some(layer), None) => ask_framework(layer)?,
+    (None, None) => ask_framework(ask_project_layer(catalogue)?)?,
+  };
+
+  let build_tool = match build_tool {
+    Some(b) => Some(framework.find_build_tool(b)?),
+    None if !framework.build_tools.is_empty() => Some(ask_build_tool(framework)?),
+    None => None,
+  };
+
+  let language = match language {
+    Some(l) => framework.check_language(l)?,
+    None if framework.languages.len() > 1 => ask_language(framework)?,
+    None => framework.default_language(),
+  };
+
+  let platform = match platform {
+    Some(p) => Some(framework.find_variant(build_tool, p)?),
+    None if !framework.variants_for(build_tool).is_empty() => {
+      Some(ask_platform(framework, build_tool)?)
+    }
+    None => None,
+  };
+
+  let path = framework.template_path(language, build_tool, platform);
+  debug!("Resolved template path {}", path.display());
+  Ok((framework, path))
+}
+
+fn choose_fullstack_member(
+  catalogue: &Catalogue,
+  template: Option<String>,
+  name: &str,
+  layers: &[&str],
+  path: &str,
+) -> Result<WorkspaceMember> {
+  if let Some(template) = template {
+    return Ok(WorkspaceMember::new(path, TemplateSource::Name(template)));
+  }
+
+  let mut frameworks = Vec::new();
+  for id in layers {
+    frameworks.extend(catalogue.find_layer(id)?.frameworks.iter().cloned());
+  }
+  let layer = CatalogueLayer {
+    id: layers.join("+"),
+    name: name.to_string(),
+    frameworks,
+  };
+
+  let (_, template) = choose_template(catalogue, Some(&layer), None, None, None, None)?;
+  Ok(WorkspaceMember::new(path, TemplateSource::Path(template)))
+}
+
+pub struct SetupWorkspaceOptions {
+  pub name: String,
+  pub tool: Option<WorkspaceTool>,
+  pub apps: Vec<MemberSpec>,
+  pub package_manager: Option<PackageManager>,
+  pub conflict: Option<FileConflictPolicy>,
+  pub fullstack: bool,
+  pub frontend: Option<String>,
+  pub backend: Option<String>,
+}
+
+pub async fn setup_workspace(
+  options: SetupWorkspaceOptions,
+  home_dir: &OxidePaths,
+  config: &OxideConfig,
+  cancel: &CancelToken,
+) -> Result<()> {
+  let mut members: Vec<WorkspaceMember> = options
+    .apps
+    .into_iter()
+    .map(|app| WorkspaceMember::new(app.path, TemplateSource::Name(app.template)))
+    .collect();
+
+  let catalogue = get_catalogue(&home_dir.registry, config).await;
+  let fullstack = if options.fullstack {
+    Some((
+      choose_fullstack_member(
+        &catalogue,
+        options.frontend,
+        "Frontend",
+        &["frontend", "meta"],
+        "apps/web",
+      )?,
+      choose_fullstack_member(
+        &catalogue,
+        options.backend,
+        "Backend",
+        &["backend"],
+        "apps/api",
+      )?,
+    ))
+  } else {
+    None
+  };
+
+  if members.is_empty() && fullstack.is_none() && is_interactive() {
+    while ask_add_member(members.is_empty())? {
+      let (framework, template) = choose_template(&catalogue, None, None, None, None, None)?;
+      let path = ask_member_path(&format!("apps/{}", framework.id))?;
+
+      let mut member = WorkspaceMember::new(path, TemplateSource::Path(template));
+      if framework.ask_user_name {
+        member = member.answer("tauri_user_name", ask_user_name()?);
+      }
+      members.push(member);
+    }
+  }
+
+  let package_manager = match options.package_manager {
+    Some(pm) => pm,
+    None => ask_package_manager()?,
+  };
+
+  let mut generator = WorkspaceGenerator::new(options.name.as_str(), package_manager)
+    .tool(options.tool)
+    .install(true)
+    .paths(home_dir.clone())
+    .config(config.clone())
+    .cancel_token(cancel.clone())
+    .conflict_policy(options.conflict)
+    .on_conflict(prompt_conflicts())
+    .events(cli_events());
+  if let Some((web, api)) = fullstack {
+    generator = generator.fullstack(web, api);
+  }
+  for member in members {
+    generator = generator.member(member);
+  }
+
+  let workspace = generator.generate().await?;
+
+  if is_quiet() {
+    println!("{}", fs::canonicalize(&workspace.path)?.display());
+  } else {
+    info!("✅ Workspace created successfully!");
+    if !workspace.members.is_empty() {
+      info!("\nMembers:");
+      for member in &workspace.members {
+        info!(
+          "  {}  ({})",
+          relative_key(member.path.strip_prefix(&workspace.path)?),
+          relative_key(&member.template)
+        );
+      }
+    }
+    if let Some(fullstack) = &workspace.fullstack {
+      info!(
+        "\nShared API types live in {} ({})",
+        TYPES_PATH, fullstack.types
+      );
+      match &fullstack.proxy {
+        Some(proxy) => info!(
+          "/api is proxied to http://localhost:{} in {}",
+          API_PORT,
+          relative_key(proxy.strip_prefix(&workspace.path)?)
+        ),
+        None => warn!(
+          "Could not configure a dev proxy in {}; forward /api to http://localhost:{} manually",
+          relative_key(&fullstack.web),
+          API_PORT
+        ),
+      }
+    }
+    info!("\nNext steps:");
+    if options.name != "." {
+      info!("  cd {}", options.name);
+    }
+    info!("  {} run dev", package_manager);
+  }
+
+  Ok(())
+}
+
+pub struct AddMemberOptions {
+  pub path: PathBuf,
+  pub template: String,
+  pub workspace: PathBuf,
+  pub package_manager: Option<PackageManager>,
+  pub conflict: Option<FileConflictPolicy>,
+  pub install: bool,
+}
+
+pub async fn add_workspace_member(
+  options: AddMemberOptions,
+  home_dir: &OxidePaths,
+  config: &OxideConfig,
+  format: OutputFormat,
+  cancel: &CancelToken,
+) -> Result<()> {
+  let added = MemberAdder::new(
+    options.workspace,
+    options.path,
+    TemplateSource::Name(options.template),
+  )
+  .package_manager(options.package_manager)
+  .install(options.install)
+  .paths(home_dir.clone())
+  .config(config.clone())
+  .cancel_token(cancel.clone())
+  .conflict_policy(options.conflict)
+  .on_conflict(prompt_conflicts())
+  .events(cli_events())
+  .add()
+  .await?;
+
+  render(&added, format)?;
+
+  if !added.installed {
+    info!(
+      "\nRun `{} install` in {} to link the new package",
+      added.package_manager, added.root
+    );
+  }
+
+  Ok(())
+}
+
+pub async fn add_feature(
+  feature: String,
+  project_dir: PathBuf,
+  conflict: Option<FileConflictPolicy>,
+  home_dir: &OxidePaths,
+  config: &OxideConfig,
+  format: OutputFormat,
+  cancel: &CancelToken,
+) -> Result<()> {
+  let applied = FeatureApplier::new(feature, &project_dir)
+    .paths(home_dir.clone())
+    .config(config.clone())
+    .cancel_token(cancel.clone())
+    .conflict_policy(conflict)
+    .on_conflict(prompt_conflicts())
+    .events(cli_events())
+    .apply()
+    .await?;
+
+  render(&applied, format)?;
+
+  if applied.patched.iter().any(|f| f == "package.json") {
+    let package_manager = ProjectMetadata::load(&project_dir)?
+      .and_then(|m| m.package_manager)
+      .unwrap_or(PackageManager::NPM);
+    info!(
+      "\nRun `{} install` to install the new dependencies",
+      package_manager
+    );
+  }
+
+  Ok(())
+}
+
+pub fn prompt_conflicts() -> impl Fn(&FileConflict) -> Result<ConflictResolution> + Send + Sync {
+  let remembered = Mutex::new(None);
+
+  move |conflict| {
+    let mut remembered = remembered
+      .lock()
+      .map_err(|_| anyhow!("Conflict prompt state is poisoned"))?;
+    if let Some(resolution) = *remembered {
+      return Ok(resolution);
+    }
+
+    let (resolution, apply_to_all) = suspend(|| ask_conflict_resolution(conflict))?;
+    if apply_to_all {
+      *remembered = Some(resolution);
+    }
+    Ok(resolution)
+  }
+}
@@ -0,0 +1,78 @@
+use std::{
+  future::Future,
+  sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+  },
+};
+
+use anyhow::Result;
+use log::warn;
+use tokio::sync::Notify;
+
+use crate::error::OxideError;
+
+#[derive(Clone, Default)]
+pub struct CancelToken {
+  inner: Arc<CancelState>,
+}
+
+#[derive(Default)]
+struct CancelState {
+  cancelled: AtomicBool,
+  notify: Notify,
+}
+
+impl CancelToken {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn install_ctrlc_handler(&self) -> Result<()> {
+    let token = self.clone();
+
+    ctrlc::set_handler(move || {
+      if token.is_cancelled() {
+        std::process::exit(130);
+      }
+      warn!("\n⚠ Interrupted! Cleaning up...");
+      token.cancel();
+    })?;
+
+    Ok(())
+  }
+
+  pub fn cancel(&self) {
+    self.inner.cancelled.store(true, Ordering::SeqCst);
+    self.inner.notify.notify_waiters();
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.inner.cancelled.load(Ordering::SeqCst)
+  }
+
+  pub fn check(&self) -> Result<()> {
+    if self.is_cancelled() {
+      return Err(OxideError::Cancelled.into());
+    }
+    Ok(())
+  }
+
+  pub async fn cancelled(&self) {
+    let notified = self.inner.notify.notified();
+    tokio::pin!(notified);
+    notified.as_mut().enable();
+
+    if self.is_cancelled() {
+      return;
+    }
+    notified.await;
+  }
+
+  pub async fn run<T>(&self, future: impl Future<Output = Result<T>>) -> Result<T> {
+    tokio::select! {
+      result = future => result,
+      _ = self.cancelled() => Err(OxideError::Cancelled.into()),
+    }
+  }
+}
@@ -1,45 +1,23 @@
 use std::{
+  collections::BTreeMap,
   fs,
-  path::{Path, PathBuf},
+  io::Write,
+  path::{Component, Path},
 };
 
 use anyhow::Result;
+use sha2::{Digest, Sha256};
+use tempfile::NamedTempFile;
+use walkdir::WalkDir;
 
-use crate::{
-  prompts::{BuildTool, Language, Platform},
-  templates::TemplateFile,
-};
-
-pub fn generate_path(
-  language: &Option<Language>,
-  build_tool: &Option<BuildTool>,
-  framework_name: &str,
-  platform: &Option<Platform>,
-) -> PathBuf {
-  let mut path = PathBuf::new();
-
-  if let Some(lg) = language {
-    match lg {
-      Language::JavaScript => path.push("js"),
-      Language::TypeScript => path.push("ts"),
-    }
-  };
+use crate::templates::TemplateFile;
 
-  if let Some(bt) = build_tool {
-    path.push(bt.to_string().to_lowercase());
-  };
-
-  if framework_name == "Qwik" {
-    path.push("vite");
-  }
-
-  path.push(framework_name.to_string().to_lowercase());
-
-  if let Some(pl) = platform {
-    path.push(pl.to_string().to_lowercase());
-  };
-
-  path
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+  let dir = path.parent().unwrap_or(Path::new("."));
+  let mut file = NamedTempFile::new_in(dir)?;
+  file.write_all(contents.as_ref())?;
+  file.persist(path)?;
+  Ok(())
 }
 
 pub fn read_dir_to_files(path: &Path) -> Result<Vec<TemplateFile>> {
@@ -72,3 +50,66 @@ pub fn read_dir_recursive(
 
   Ok(())
 }
+
+pub fn dir_stats(path: &Path) -> (usize, u64) {
+  WalkDir::new(path)
+    .into_iter()
+    .filter_map(|e| e.ok())
+    .filter(|e| e.file_type().is_file())
+    .fold((0, 0), |(count, size), e| {
+      (count + 1, size + e.metadata().map(|m| m.len()).unwrap_or(0))
+    })
+}
+
+pub fn format_size(bytes: u64) -> String {
+  const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+  let mut size = bytes as f64;
+  let mut unit = 0;
+  while size >= 1024.0 && unit < UNITS.len() - 1 {
+    size /= 1024.0;
+    unit += 1;
+  }
+
+  if unit == 0 {
+    format!("{} {}", bytes, UNITS[unit])
+  } else {
+    format!("{:.1} {}", size, UNITS[unit])
+  }
+}
+
+pub fn hash_file(path: &Path) -> Result<String> {
+  let contents = fs::read(path)?;
+  Ok(format!("{:x}", Sha256::digest(&contents)))
+}
+
+pub fn hash_dir(path: &Path) -> Result<BTreeMap<String, String>> {
+  let mut hashes = BTreeMap::new();
+
+  for entry in WalkDir::new(path) {
+    let entry = entry?;
+    if !entry.file_type().is_file() {
+      continue;
+    }
+
+    let relative_path = relative_key(entry.path().strip_prefix(path)?);
+    hashes.insert(relative_path, hash_file(entry.path())?);
+  }
+
+  Ok(hashes)
+}
+
+pub fn relative_key(path: &Path) -> String {
+  path
+    .components()
+    .map(|c| c.as_os_str().to_string_lossy())
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+pub fn is_relative_normal(path: &Path) -> bool {
+  path.components().count() > 0
+    && path
+      .components()
+      .all(|component| matches!(component, Component::Normal(_)))
+}
@@ -7,7 +7,10 @@ use anyhow::Result;
 use reqwest::{Client, header::USER_AGENT};
 use serde::Deserialize;
 
-use crate::cache::is_template_installed;
+use crate::{
+  project::events::{EventSink, GeneratorEvent},
+  utils::{cancel::CancelToken, http::send},
+};
 
 #[derive(Deserialize)]
 struct GithubEntry {
@@ -18,97 +21,76 @@ struct GithubEntry {
 
   download_url: Option<String>,
   url: String,
-}
 
-#[derive(Deserialize)]
-struct OxideTemplateConfig {
-  name: String,
+  #[serde(default)]
+  size: u64,
 }
 
 pub async fn download_dir(
   client: &Client,
   api_url: &str,
   path: &Path,
-  is_root: bool,
-  tempate_path: &Path,
+  cancel: &CancelToken,
+  events: &EventSink,
 ) -> Result<()> {
   fs::create_dir_all(path)?;
 
-  let entries: Vec<GithubEntry> = client
-    .get(api_url)
-    .header(USER_AGENT, "oxide")
-    .send()
-    .await?
-    .error_for_status()?
-    .json()
+  let entries: Vec<GithubEntry> = cancel
+    .run(async {
+      Ok(
+        send(client.get(api_url).header(USER_AGENT, "oxide"))
+          .await?
+          .error_for_status()?
+          .json()
+          .await?,
+      )
+    })
     .await?;
 
-  let is_indstalled = if is_root {
-    let template_name = fetch_template_name(client, &entries).await?;
-    is_template_installed(&template_name, tempate_path)?
-  } else {
-    false
-  };
-
-  if !is_indstalled {
-    for entry in entries {
-      let local_path: PathBuf = path.join(&entry.name);
-
-      match entry.entry_type.as_str() {
-        "file" => {
-          if let Some(download_url) = entry.download_url {
-            let bytes = client
-              .get(download_url)
-              .header(USER_AGENT, "oxide")
-              .send()
-              .await?
-              .bytes()
-              .await?;
-
-            fs::write(&local_path, bytes)?;
-            println!("✓ {}", local_path.display());
-          }
-        }
-        "dir" => {
-          Box::pin(download_dir(
-            client,
-            &entry.url,
-            &local_path,
-            false,
-            tempate_path,
-          ))
-          .await?;
+  let files: Vec<&GithubEntry> = entries.iter().filter(|e| e.entry_type == "file").collect();
+  events.emit(GeneratorEvent::DownloadQueued {
+    files: files.len() as u64,
+    bytes: files.iter().map(|e| e.size).sum(),
+  });
+
+  for entry in entries {
+    let local_path: PathBuf = path.join(&entry.name);
+
+    match entry.entry_type.as_str() {
+      "file" => {
+        if let Some(download_url) = entry.download_url {
+          let bytes = cancel
+            .run(async {
+              Ok(
+                send(client.get(download_url).header(USER_AGENT, "oxide"))
+                  .await?
+                  .error_for_status()?
+                  .bytes()
+                  .await?,
+              )
+            })
+            .await?;
+
+          fs::write(&local_path, &bytes)?;
+          events.emit(GeneratorEvent::FileDownloaded {
+            path: local_path,
+            bytes: bytes.len() as u64,
+          });
         }
-        _ => {}
       }
+      "dir" => {
+        Box::pin(download_dir(
+          client,
+          &entry.url,
+          &local_path,
+          cancel,
+          events,
+        ))
+        .await?;
+      }
+      _ => {}
     }
-
-    Ok(())
-  } else {
-    println!("This template is installed in");
-    std::process::exit(0)
   }
-}
-
-async fn fetch_template_name(client: &Client, entries: &[GithubEntry]) -> Result<String> {
-  let entry = entries
-    .iter()
-    .find(|e| e.name == "oxide.template.json")
-    .ok_or_else(|| anyhow::anyhow!("oxide.template.json not found"))?;
-
-  let download_url = entry
-    .download_url
-    .as_ref()
-    .ok_or_else(|| anyhow::anyhow!("No download URL for oxide.template.json"))?;
-
-  let text = client
-    .get(download_url)
-    .header(USER_AGENT, "oxide")
-    .send()
-    .await?
-    .text()
-    .await?;
 
-  let config: OxideTemplateConfig = serde_json::from_str(&text)?;
-  Ok(config.name)
+  Ok(())
 }
@@ -0,0 +1,83 @@
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use log::{debug, trace};
+use reqwest::{Certificate, Client, NoProxy, Proxy, RequestBuilder, Response, StatusCode};
+
+use crate::{config::OxideConfig, error::OxideError};
+
+pub fn build_client(config: &OxideConfig) -> Result<Client> {
+  let mut builder = Client::builder()
+    .user_agent("oxide")
+    .connect_timeout(Duration::from_secs(config.connect_timeout_secs))
+    .read_timeout(Duration::from_secs(config.read_timeout_secs));
+
+  if let Some(proxy_url) = &config.proxy {
+    let no_proxy = match &config.no_proxy {
+      Some(list) => NoProxy::from_string(list),
+      None => NoProxy::from_env(),
+    };
+    let proxy = Proxy::all(proxy_url)
+      .with_context(|| format!("Invalid proxy URL '{}'", proxy_url))?
+      .no_proxy(no_proxy);
+    builder = builder.proxy(proxy);
+  }
+
+  for bundle in ca_bundles(config) {
+    let pem = fs::read(&bundle)
+      .with_context(|| format!("Could not read CA bundle {}", bundle.display()))?;
+    let certificates = Certificate::from_pem_bundle(&pem)
+      .with_context(|| format!("Invalid PEM CA bundle {}", bundle.display()))?;
+
+    for certificate in certificates {
+      builder = builder.add_root_certificate(certificate);
+    }
+  }
+
+  Ok(builder.build()?)
+}
+
+fn ca_bundles(config: &OxideConfig) -> Vec<PathBuf> {
+  let mut bundles = config.ca_certificates.clone();
+
+  if let Some(cert_file) = env::var_os("SSL_CERT_FILE").filter(|v| !v.is_empty()) {
+    bundles.push(PathBuf::from(cert_file));
+  }
+
+  bundles
+}
+
+pub async fn send(request: RequestBuilder) -> Result<Response> {
+  let (client, request) = request.build_split();
+  let request = request?;
+  debug!("{} {}", request.method(), request.url());
+
+  let response = client
+    .execute(request)
+    .await
+    .map_err(OxideError::unreachable)?;
+  trace!("{} {}", response.status(), response.url());
+
+  let header = |name: &str| {
+    response
+      .headers()
+      .get(name)
+      .and_then(|v| v.to_str().ok())
+      .map(String::from)
+  };
+
+  let exhausted = header("x-ratelimit-remaining").as_deref() == Some("0");
+  if response.status() == StatusCode::TOO_MANY_REQUESTS
+    || (response.status() == StatusCode::FORBIDDEN && exhausted)
+  {
+    let reset = header("x-ratelimit-reset")
+      .and_then(|v| v.parse::<i64>().ok())
+      .and_then(|secs| DateTime::from_timestamp(secs, 0))
+      .map(|t| t.with_timezone(&Local).format("%H:%M:%S").to_string());
+
+    return Err(OxideError::RateLimited { reset }.into());
+  }
+
+  Ok(response)
+}
@@ -1,4 +1,5 @@
+pub mod cancel;
 pub mod fs;
 pub mod git;
-pub mod setup;
+pub mod http;
 pub mod validate;
@@ -1,5 +1,3 @@
-use std::path::Path;
-
 use anyhow::{Result, anyhow};
 use regex::Regex;
 
@@ -8,9 +6,6 @@ pub fn validate_project_name(name: &str) -> Result<()> {
     return Ok(());
   }
 
-  if Path::new(name).exists() {
-    return Err(anyhow!("Directory '{}' already exists!", name));
-  }
   if name.is_empty() {
     return Err(anyhow!("Project name cannot be empty"));
   }